@@ -0,0 +1,61 @@
+use alloy_signer_local::PrivateKeySigner;
+use polymarket_rs::config::{chains, get_contract_config};
+use polymarket_rs::orders::OrderBuilder;
+use polymarket_rs::types::{CreateOrderOptions, ExtraOrderArgs, OrderArgs, Side, SignatureType};
+use polymarket_rs::Result;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Build and sign an order for the Polygon Amoy testnet
+///
+/// This example does not talk to the API: it shows which testnet contracts
+/// are used and prints the signed order so it can be inspected before
+/// pointing a client at a testnet CLOB.
+fn main() -> Result<()> {
+    // Use PRIVATE_KEY if set, otherwise a throwaway key is fine for testnet
+    let signer = match std::env::var("PRIVATE_KEY") {
+        Ok(private_key) => PrivateKeySigner::from_str(&private_key).expect("Invalid private key"),
+        Err(_) => PrivateKeySigner::random(),
+    };
+
+    let chain_id = chains::POLYGON_AMOY_TESTNET;
+
+    println!("Signer address: {}", signer.address());
+    println!("Chain ID: {}", chain_id);
+
+    // Contracts used for standard and negative risk markets on Amoy
+    for neg_risk in [false, true] {
+        let config = get_contract_config(chain_id, neg_risk)?;
+        println!("\nContracts (neg_risk = {}):", neg_risk);
+        println!("  Exchange: {}", config.exchange);
+        println!("  Collateral: {}", config.collateral);
+        println!("  Conditional tokens: {}", config.conditional_tokens);
+    }
+
+    // Build a limit order signed against the testnet exchange
+    let order_builder = OrderBuilder::new(signer, Some(SignatureType::Eoa), None);
+    let order_args = OrderArgs::new(
+        "1234",
+        Decimal::from_str("0.50").unwrap(),
+        Decimal::from_str("10.0").unwrap(),
+        Side::Buy,
+    );
+
+    let signed_order = order_builder.create_order(
+        chain_id,
+        &order_args,
+        0,
+        &ExtraOrderArgs::default(),
+        CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false),
+    )?;
+
+    println!("\nSigned testnet order:");
+    println!("  Maker: {}", signed_order.maker);
+    println!("  Maker amount: {}", signed_order.maker_amount);
+    println!("  Taker amount: {}", signed_order.taker_amount);
+    println!("  Signature: {}", signed_order.signature);
+
+    Ok(())
+}
@@ -16,11 +16,7 @@ async fn get_market_token_id() -> Result<Vec<String>, Box<dyn std::error::Error>
     match client.get_markets(Some(params)).await {
         Ok(markets) => Ok(markets
             .iter()
-            .map(|m| {
-                let token_ids: Vec<String> =
-                    serde_json::from_str(&m.clob_token_ids.as_ref().unwrap()).unwrap_or_default();
-                token_ids
-            })
+            .filter_map(|m| m.token_ids().ok())
             .flatten()
             .collect()),
         Err(e) => {
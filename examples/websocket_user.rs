@@ -3,7 +3,7 @@ use std::time::Duration;
 
 use futures_util::StreamExt;
 use polymarket_rs::types::UserWsEvent;
-use polymarket_rs::websocket::{ReconnectConfig, ReconnectingStream, UserWsClient};
+use polymarket_rs::websocket::{ReconnectConfig, UserWsClient};
 use polymarket_rs::{AuthenticatedClient, PrivateKeySigner};
 
 #[tokio::main]
@@ -41,22 +41,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_attempts: None, // Unlimited reconnection attempts
     };
 
-    // Create a reconnecting stream that will automatically reconnect on disconnection
-    let api_creds_clone = api_creds.clone();
-    let mut stream = ReconnectingStream::new(config, move || {
-        let client = client.clone();
-        let creds = api_creds_clone.clone();
-        async move {
-            println!("🔌 Connecting to user WebSocket...");
-            let result = client.subscribe_with_creds(&creds).await;
-            if result.is_ok() {
-                println!("✅ Connected successfully!");
-            }
-            result
-        }
-    });
+    // Create a reconnecting stream; the auth message is re-sent on every reconnect.
+    // Pass condition IDs instead of an empty list to only receive events for those markets.
+    let (mut stream, handle) = client.subscribe_reconnecting(api_creds, Vec::new(), config);
+    println!(
+        "Subscribed markets: {:?} (empty = all)",
+        handle.subscribed_markets()
+    );
 
-    println!("Connected! Waiting for events...\n");
+    println!("Waiting for events...\n");
 
     // Process events as they arrive
     let mut event_count = 0;
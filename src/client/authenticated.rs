@@ -1,8 +1,14 @@
+use crate::clock::{Clock, SystemClock};
 use crate::error::{Error, Result};
 use crate::http::{create_l1_headers, create_l2_headers, HttpClient};
+use crate::request::{paginate, Paginated, QueryBuilder, INITIAL_CURSOR};
 use crate::signing::EthSigner;
-use crate::types::{ApiCreds, ApiKeysResponse, BalanceAllowanceParams};
+use crate::types::{
+    ApiCreds, ApiKeysResponse, BalanceAllowanceParams, RewardEarning, RewardEarningsResponse,
+};
 use alloy_primitives::{Address, U256};
+use chrono::NaiveDate;
+use futures_util::TryStreamExt;
 
 /// Client for authenticated operations
 ///
@@ -17,6 +23,7 @@ pub struct AuthenticatedClient {
     chain_id: u64,
     api_creds: Option<ApiCreds>,
     funder: Option<Address>,
+    clock: Box<dyn Clock>,
 }
 
 impl AuthenticatedClient {
@@ -48,9 +55,27 @@ impl AuthenticatedClient {
             chain_id,
             api_creds,
             funder,
+            clock: Box::new(SystemClock),
         }
     }
 
+    /// Replace the underlying HTTP client
+    ///
+    /// Use this with a client built by [`HttpClient::builder`] to tune connection
+    /// pooling. The HTTP client's base URL is used for all requests.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Use a custom clock instead of the system time
+    ///
+    /// The clock decides the default day for [`get_user_rewards`](Self::get_user_rewards).
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
     /// Get the API credentials if available
     ///
     /// Returns a reference to the API credentials if they were provided when creating
@@ -132,17 +157,17 @@ impl AuthenticatedClient {
     /// This creates a new API key for the signer's address.
     /// Requires wallet signature.
     pub async fn create_api_key(&self, nonce: Option<U256>) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, nonce)?;
+        let headers = || create_l1_headers(&self.signer, self.chain_id, nonce);
         self.http_client
-            .post("/auth/api-key", &serde_json::json!({}), Some(headers))
+            .post_signed("/auth/api-key", &serde_json::json!({}), headers)
             .await
     }
 
     /// Derive API key from existing credentials (L1 authentication required)
     pub async fn derive_api_key(&self) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, None)?;
+        let headers = || create_l1_headers(&self.signer, self.chain_id, None);
         self.http_client
-            .get("/auth/derive-api-key", Some(headers))
+            .get_signed("/auth/derive-api-key", headers)
             .await
     }
 
@@ -164,8 +189,8 @@ impl AuthenticatedClient {
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", "/auth/api-keys", None)?;
-        self.http_client.get("/auth/api-keys", Some(headers)).await
+            || create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", "/auth/api-keys", None);
+        self.http_client.get_signed("/auth/api-keys", headers).await
     }
 
     /// Delete an API key (L2 authentication required)
@@ -176,9 +201,9 @@ impl AuthenticatedClient {
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, api_creds, "DELETE", "/auth/api-key", None)?;
+            || create_l2_headers::<_, ()>(&self.signer, api_creds, "DELETE", "/auth/api-key", None);
         self.http_client
-            .delete("/auth/api-key", Some(headers))
+            .delete_signed("/auth/api-key", headers)
             .await
     }
 
@@ -197,25 +222,15 @@ impl AuthenticatedClient {
 
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/balance-allowance";
-        let headers = create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None)?;
+        let headers =
+            || create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None);
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let request_path = QueryBuilder::new()
+            .extend(params.to_query_params())
+            .append_to(base_path);
 
-        self.http_client.get(&request_path, Some(headers)).await
+        self.http_client.get_signed(&request_path, headers).await
     }
 
     /// Update balance allowance (L2 authentication required)
@@ -225,15 +240,17 @@ impl AuthenticatedClient {
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
-        let headers = create_l2_headers::<_, ()>(
-            &self.signer,
-            api_creds,
-            "GET",
-            "/balance-allowance/update",
-            None,
-        )?;
+        let headers = || {
+            create_l2_headers::<_, ()>(
+                &self.signer,
+                api_creds,
+                "GET",
+                "/balance-allowance/update",
+                None,
+            )
+        };
         self.http_client
-            .get("/balance-allowance/update", Some(headers))
+            .get_signed("/balance-allowance/update", headers)
             .await
     }
 
@@ -245,8 +262,8 @@ impl AuthenticatedClient {
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", "/notifications", None)?;
-        self.http_client.get("/notifications", Some(headers)).await
+            || create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", "/notifications", None);
+        self.http_client.get_signed("/notifications", headers).await
     }
 
     /// Drop (delete) notifications (L2 authentication required)
@@ -257,18 +274,55 @@ impl AuthenticatedClient {
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
         let body = serde_json::json!({ "ids": ids });
-        let headers = create_l2_headers(
-            &self.signer,
-            api_creds,
-            "DELETE",
-            "/notifications",
-            Some(&body),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                api_creds,
+                "DELETE",
+                "/notifications",
+                Some(&body),
+            )
+        };
         self.http_client
-            .delete_with_body("/notifications", &body, Some(headers))
+            .delete_with_body_signed("/notifications", &body, headers)
             .await
     }
 
+    /// Get reward earnings for the current user on a given day (L2 authentication required)
+    ///
+    /// Follows pagination until all earnings for the day have been fetched. The
+    /// endpoint only reports earnings for the account owning the API credentials,
+    /// so there is no `user` argument; query another account with its own client.
+    ///
+    /// # Arguments
+    /// * `date` - The day to query (defaults to today in UTC, read from the client's clock)
+    pub async fn get_user_rewards(&self, date: Option<NaiveDate>) -> Result<Vec<RewardEarning>> {
+        let api_creds = self
+            .api_creds
+            .as_ref()
+            .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
+
+        let date = date.unwrap_or_else(|| self.clock.now().date_naive());
+
+        // IMPORTANT: Sign the base path WITHOUT query parameters
+        let base_path = "/rewards/user";
+
+        paginate(INITIAL_CURSOR, |pagination| async move {
+            let headers =
+                || create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None);
+            let request_path = QueryBuilder::new()
+                .push("date", date.format("%Y-%m-%d"))
+                .extend(pagination.to_query_params())
+                .append_to(base_path);
+
+            let response: RewardEarningsResponse =
+                self.http_client.get_signed(&request_path, headers).await?;
+            Ok(response.into_page())
+        })
+        .try_collect()
+        .await
+    }
+
     /// Get the signer's address
     pub fn get_address(&self) -> String {
         format!("{:?}", self.signer.address())
@@ -282,3 +336,79 @@ impl AuthenticatedClient {
         self.funder
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use alloy_signer_local::PrivateKeySigner;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serve reward earnings, reporting one earning for the requested `date`
+    async fn mock_server() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut tcp, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = vec![0; 8192];
+                    let n = tcp.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]);
+                    let date = request
+                        .split_once("date=")
+                        .map(|(_, rest)| &rest[..10])
+                        .unwrap_or_default();
+
+                    let body = serde_json::json!({
+                        "data": [{
+                            "condition_id": "0xmarket",
+                            "asset_address": "0xusdc",
+                            "earnings": "1.5",
+                            "date": date
+                        }],
+                        "limit": 100,
+                        "count": 1,
+                        "next_cursor": "LTE="
+                    })
+                    .to_string();
+
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = tcp.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_get_user_rewards_defaults_to_clock_date() {
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "c2VjcmV0".to_string(),
+            "passphrase".to_string(),
+        );
+        // 2023-11-14T22:13:20Z
+        let clock = FixedClock::from_unix_secs(1_700_000_000).unwrap();
+        let client = AuthenticatedClient::new(
+            mock_server().await,
+            PrivateKeySigner::random(),
+            137,
+            Some(creds),
+            None,
+        )
+        .with_clock(clock);
+
+        let earnings = client.get_user_rewards(None).await.unwrap();
+        assert_eq!(earnings.len(), 1);
+        assert_eq!(
+            earnings[0].date,
+            NaiveDate::from_ymd_opt(2023, 11, 14).unwrap()
+        );
+    }
+}
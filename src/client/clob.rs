@@ -1,12 +1,17 @@
 use crate::error::Result;
 use crate::http::HttpClient;
-use crate::request::PaginationParams;
+use crate::neg_risk::{quote_conversion, ConversionQuote};
+use crate::request::{encode_path_segment, PaginationParams, QueryBuilder};
 use crate::types::{
-    BookParams, ConditionId, Market, MarketsResponse, MidpointResponse, NegRiskResponse,
-    OrderBookSummary, PriceHistoryResponse, PriceResponse, SimplifiedMarketsResponse,
-    SpreadResponse, TickSizeResponse, TokenId,
+    BookMetrics, BookParams, ConditionId, Market, MarketsResponse, MidpointResponse,
+    NegRiskResponse, OrderBookSummary, PriceHistoryResponse, PriceResponse,
+    SimplifiedMarketsResponse, SpreadResponse, TickSizeResponse, Ticker, TokenId,
 };
 use crate::Side;
+use futures_util::future::{try_join, try_join_all};
+use futures_util::{stream, StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 /// Client for CLOB (Central Limit Order Book) market data APIs
 ///
@@ -17,6 +22,9 @@ pub struct ClobClient {
 }
 
 impl ClobClient {
+    /// Maximum concurrent requests made by [`get_market_params`](Self::get_market_params)
+    pub const MARKET_PARAMS_CONCURRENCY: usize = 8;
+
     /// Create a new ClobClient
     ///
     /// # Arguments
@@ -27,6 +35,15 @@ impl ClobClient {
         }
     }
 
+    /// Replace the underlying HTTP client
+    ///
+    /// Use this with a client built by [`HttpClient::builder`] to tune connection
+    /// pooling. The HTTP client's base URL is used for all requests.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
     /// Check if the server is responsive
     pub async fn get_ok(&self) -> Result<serde_json::Value> {
         self.http_client.get("/", None).await
@@ -42,7 +59,9 @@ impl ClobClient {
     /// # Arguments
     /// * `token_id` - The token ID to query
     pub async fn get_midpoint(&self, token_id: &TokenId) -> Result<MidpointResponse> {
-        let path = format!("/midpoint?token_id={}", token_id.as_str());
+        let path = QueryBuilder::new()
+            .push("token_id", token_id.as_str())
+            .append_to("/midpoint");
         self.http_client.get(&path, None).await
     }
 
@@ -63,8 +82,10 @@ impl ClobClient {
     /// * `token_id` - The token ID to query
     /// * `side` - Optional side (BUY or SELL)
     pub async fn get_price(&self, token_id: &TokenId, side: Side) -> Result<PriceResponse> {
-        let mut path = format!("/price?token_id={}", token_id.as_str());
-        path.push_str(&format!("&side={}", side.as_str()));
+        let path = QueryBuilder::new()
+            .push("token_id", token_id.as_str())
+            .push("side", side.as_str())
+            .append_to("/price");
         self.http_client.get(&path, None).await
     }
 
@@ -92,21 +113,21 @@ impl ClobClient {
         end_ts: Option<u64>,
         fidelity: Option<u64>,
     ) -> Result<PriceHistoryResponse> {
-        let mut path = format!("/prices-history?market={}", token_id.as_str());
-        path.push_str(&format!("&interval={}", interval));
-        path.push_str(&format!("&fidelity={}", fidelity.unwrap_or(10)));
-        if let Some(start) = start_ts {
-            path.push_str(&format!("&startTs={}", start));
-        }
-        if let Some(end) = end_ts {
-            path.push_str(&format!("&endTs={}", end));
-        }
+        let path = QueryBuilder::new()
+            .push("market", token_id.as_str())
+            .push("interval", interval)
+            .push("fidelity", fidelity.unwrap_or(10))
+            .push_opt("startTs", start_ts)
+            .push_opt("endTs", end_ts)
+            .append_to("/prices-history");
         self.http_client.get(&path, None).await
     }
 
     /// Get the bid/ask spread for a token
     pub async fn get_spread(&self, token_id: &TokenId) -> Result<SpreadResponse> {
-        let path = format!("/spread?token_id={}", token_id.as_str());
+        let path = QueryBuilder::new()
+            .push("token_id", token_id.as_str())
+            .append_to("/spread");
         self.http_client.get(&path, None).await
     }
 
@@ -120,22 +141,90 @@ impl ClobClient {
 
     /// Get the minimum tick size for a token
     pub async fn get_tick_size(&self, token_id: &TokenId) -> Result<TickSizeResponse> {
-        let path = format!("/tick-size?token_id={}", token_id.as_str());
+        let path = QueryBuilder::new()
+            .push("token_id", token_id.as_str())
+            .append_to("/tick-size");
         self.http_client.get(&path, None).await
     }
 
     /// Get whether a market uses negative risk
     pub async fn get_neg_risk(&self, condition_id: &ConditionId) -> Result<NegRiskResponse> {
-        let path = format!("/neg-risk?condition_id={}", condition_id.as_str());
+        let path = QueryBuilder::new()
+            .push("condition_id", condition_id.as_str())
+            .append_to("/neg-risk");
         self.http_client.get(&path, None).await
     }
 
+    /// Get top-of-book metrics for a token
+    ///
+    /// Fetches the order book and tick size concurrently and derives the best bid,
+    /// best ask, midpoint and spread from the book. Meant for quoting loops that
+    /// would otherwise poll each metric separately.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to query
+    pub async fn get_book_metrics(&self, token_id: &TokenId) -> Result<BookMetrics> {
+        let (book, tick_size) =
+            try_join(self.get_order_book(token_id), self.get_tick_size(token_id)).await?;
+        Ok(BookMetrics::from_book(&book, tick_size.minimum_tick_size))
+    }
+
+    /// Get the best bid, best ask and midpoint of every outcome of a market
+    ///
+    /// Fetches the market for its tokens, then all of their order books in a
+    /// single batch request.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The market's condition ID
+    pub async fn get_ticker(&self, condition_id: &ConditionId) -> Result<Ticker> {
+        let market = self.get_market(condition_id).await?;
+        let params: Vec<BookParams> = market
+            .tokens
+            .iter()
+            .map(|token| BookParams::new(&token.token_id, Side::Buy))
+            .collect();
+        let books = self.get_order_books(&params).await?;
+
+        Ok(Ticker::from_books(
+            market.condition_id,
+            &market.tokens,
+            &books,
+        ))
+    }
+
+    /// Quote converting a No share into Yes shares of the other outcomes
+    ///
+    /// Fetches the order books concurrently and compares the best ask of the No
+    /// token against the summed best bids of the Yes basket. All tokens must belong
+    /// to the same neg-risk event; see [`crate::neg_risk`].
+    ///
+    /// # Arguments
+    /// * `no_token` - The No token that would be converted
+    /// * `other_yes_tokens` - The Yes tokens of every other outcome in the event
+    ///
+    /// # Returns
+    /// `None` if any of the required book sides is empty
+    pub async fn get_neg_risk_conversion_quote(
+        &self,
+        no_token: &TokenId,
+        other_yes_tokens: &[TokenId],
+    ) -> Result<Option<ConversionQuote>> {
+        let (no_book, yes_books) = try_join(
+            self.get_order_book(no_token),
+            try_join_all(other_yes_tokens.iter().map(|id| self.get_order_book(id))),
+        )
+        .await?;
+        Ok(quote_conversion(&no_book, &yes_books))
+    }
+
     /// Get the order book for a token
     ///
     /// # Arguments
     /// * `token_id` - The token ID to query
     pub async fn get_order_book(&self, token_id: &TokenId) -> Result<OrderBookSummary> {
-        let path = format!("/book?token_id={}", token_id.as_str());
+        let path = QueryBuilder::new()
+            .push("token_id", token_id.as_str())
+            .append_to("/book");
         self.http_client.get(&path, None).await
     }
 
@@ -146,7 +235,9 @@ impl ClobClient {
 
     /// Get the last trade price for a token
     pub async fn get_last_trade_price(&self, token_id: &TokenId) -> Result<PriceResponse> {
-        let path = format!("/last-trade-price?token_id={}", token_id.as_str());
+        let path = QueryBuilder::new()
+            .push("token_id", token_id.as_str())
+            .append_to("/last-trade-price");
         self.http_client.get(&path, None).await
     }
 
@@ -170,14 +261,14 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/sampling-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = QueryBuilder::new()
+            .extend(
+                pagination
+                    .as_ref()
+                    .map(|p| p.to_query_params())
+                    .unwrap_or_default(),
+            )
+            .append_to("/sampling-markets");
         self.http_client.get(&path, None).await
     }
 
@@ -189,14 +280,14 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/sampling-simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = QueryBuilder::new()
+            .extend(
+                pagination
+                    .as_ref()
+                    .map(|p| p.to_query_params())
+                    .unwrap_or_default(),
+            )
+            .append_to("/sampling-simplified-markets");
         self.http_client.get(&path, None).await
     }
 
@@ -208,14 +299,14 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = QueryBuilder::new()
+            .extend(
+                pagination
+                    .as_ref()
+                    .map(|p| p.to_query_params())
+                    .unwrap_or_default(),
+            )
+            .append_to("/markets");
         self.http_client.get(&path, None).await
     }
 
@@ -224,26 +315,63 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = QueryBuilder::new()
+            .extend(
+                pagination
+                    .as_ref()
+                    .map(|p| p.to_query_params())
+                    .unwrap_or_default(),
+            )
+            .append_to("/simplified-markets");
         self.http_client.get(&path, None).await
     }
 
     /// Get a specific market by condition ID
     pub async fn get_market(&self, condition_id: &ConditionId) -> Result<Market> {
-        let path = format!("/markets/{}", condition_id.as_str());
+        let path = format!("/markets/{}", encode_path_segment(condition_id.as_str()));
         self.http_client.get(&path, None).await
     }
 
+    /// Get order parameters for many markets at once
+    ///
+    /// Fetches every market concurrently (at most [`Self::MARKET_PARAMS_CONCURRENCY`]
+    /// requests in flight) and extracts what is needed to build
+    /// [`CreateOrderOptions`](crate::types::CreateOrderOptions) for each of them.
+    /// Duplicate condition IDs are fetched once.
+    ///
+    /// # Arguments
+    /// * `condition_ids` - Condition IDs of the markets
+    ///
+    /// # Returns
+    /// A map from condition ID to `(minimum_order_size, minimum_tick_size, neg_risk)`
+    pub async fn get_market_params(
+        &self,
+        condition_ids: &[&str],
+    ) -> Result<HashMap<String, (Decimal, Decimal, bool)>> {
+        let mut unique = condition_ids.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        stream::iter(unique)
+            .map(|id| async move { self.get_market(&ConditionId::new(id)).await })
+            .buffer_unordered(Self::MARKET_PARAMS_CONCURRENCY)
+            .map_ok(|market| {
+                (
+                    market.condition_id,
+                    (
+                        market.minimum_order_size,
+                        market.minimum_tick_size,
+                        market.neg_risk,
+                    ),
+                )
+            })
+            .try_collect()
+            .await
+    }
+
     /// Get a specific market by slug
     pub async fn get_market_by_slug(&self, market_slug: &str) -> Result<Market> {
-        let path = format!("/markets/slug/{}", market_slug);
+        let path = format!("/markets/slug/{}", encode_path_segment(market_slug));
         self.http_client.get(&path, None).await
     }
 
@@ -255,7 +383,10 @@ impl ClobClient {
         &self,
         condition_id: &ConditionId,
     ) -> Result<serde_json::Value> {
-        let path = format!("/live-activity/events/{}", condition_id.as_str());
+        let path = format!(
+            "/live-activity/events/{}",
+            encode_path_segment(condition_id.as_str())
+        );
         self.http_client.get(&path, None).await
     }
 }
@@ -1,7 +1,14 @@
 use crate::error::Result;
 use crate::http::HttpClient;
-use crate::request::{ActivityQueryParams, TradeQueryParams};
-use crate::types::{Activity, ClosedPosition, Position, PositionValue, Trade};
+use crate::request::{
+    paginate, ActivityQueryParams, MaybePaginated, PageResponse, Paginated, PaginationParams,
+    PositionParams, QueryBuilder, TradeQueryParams, INITIAL_CURSOR,
+};
+use crate::types::{
+    Activity, ActivityType, ClosedPosition, Position, PositionValue, Trade, TradeParams,
+};
+use futures_util::Stream;
+use rust_decimal::Decimal;
 
 /// Client for accessing position and portfolio data
 ///
@@ -22,6 +29,15 @@ impl DataClient {
         }
     }
 
+    /// Replace the underlying HTTP client
+    ///
+    /// Use this with a client built by [`HttpClient::builder`] to tune connection
+    /// pooling. The HTTP client's base URL is used for all requests.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
     /// Get all positions for a user
     ///
     /// # Arguments
@@ -30,10 +46,111 @@ impl DataClient {
     /// # Returns
     /// A list of positions owned by the user
     pub async fn get_positions(&self, user: &str) -> Result<Vec<Position>> {
-        let path = format!("/positions?user={}", user);
+        self.get_positions_with_params(user, None).await
+    }
+
+    /// Get a user's positions, optionally filtered by market or asset
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address
+    /// * `params` - Optional filters (market, asset_id)
+    ///
+    /// # Returns
+    /// The user's positions matching the filters
+    pub async fn get_positions_with_params(
+        &self,
+        user: &str,
+        params: Option<PositionParams>,
+    ) -> Result<Vec<Position>> {
+        let path = QueryBuilder::new()
+            .push("user", user)
+            .extend(
+                params
+                    .as_ref()
+                    .map(|p| p.to_query_params())
+                    .unwrap_or_default(),
+            )
+            .append_to("/positions");
         self.http_client.get(&path, None).await
     }
 
+    /// Get one page of a user's positions
+    ///
+    /// Pass [`PaginationParams::initial`] for the first page, then the returned
+    /// cursor for the following ones. The cursor is `None` on the last page. If the
+    /// server answers with a plain list, it is returned as a single last page.
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address
+    /// * `pagination` - Cursor of the page to fetch
+    ///
+    /// # Returns
+    /// The positions of the page and the cursor of the next page
+    pub async fn get_positions_page(
+        &self,
+        user: &str,
+        pagination: PaginationParams,
+    ) -> Result<(Vec<Position>, Option<String>)> {
+        let path = QueryBuilder::new()
+            .push("user", user)
+            .extend(pagination.to_query_params())
+            .append_to("/positions");
+        let response: MaybePaginated<Position> = self.http_client.get(&path, None).await?;
+        let page = response.into_page();
+        Ok((page.data, page.next_cursor))
+    }
+
+    /// Stream every position of a user, fetching pages as needed
+    ///
+    /// Follows the cursors returned by [`get_positions_page`](Self::get_positions_page)
+    /// until the last page. The stream ends after the first error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use polymarket_rs::client::DataClient;
+    ///
+    /// # async fn example() -> polymarket_rs::Result<()> {
+    /// let client = DataClient::new("https://data-api.polymarket.com");
+    /// let positions: Vec<_> = client.positions_stream("0xuser").try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn positions_stream<'a>(
+        &'a self,
+        user: &'a str,
+    ) -> impl Stream<Item = Result<Position>> + 'a {
+        paginate(INITIAL_CURSOR, move |pagination| async move {
+            let (data, next_cursor) = self.get_positions_page(user, pagination).await?;
+            Ok(PageResponse { data, next_cursor })
+        })
+    }
+
+    /// Get positions from resolved markets that can be redeemed
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address
+    ///
+    /// # Returns
+    /// The user's positions with `redeemable == true`
+    pub async fn get_redeemable(&self, user: &str) -> Result<Vec<Position>> {
+        let positions = self.get_positions(user).await?;
+        Ok(positions.into_iter().filter(|p| p.redeemable).collect())
+    }
+
+    /// Get the total current value of a user's redeemable positions
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address
+    ///
+    /// # Returns
+    /// The sum of `current_value` over the positions returned by [`get_redeemable`](Self::get_redeemable)
+    pub async fn total_redeemable_value(&self, user: &str) -> Result<Decimal> {
+        let positions = self.get_redeemable(user).await?;
+        Ok(positions.iter().map(|p| p.current_value).sum())
+    }
+
     /// Get the total value of positions for a user
     ///
     /// # Arguments
@@ -42,7 +159,7 @@ impl DataClient {
     /// # Returns
     /// A list of position values for the user
     pub async fn get_positions_value(&self, user: &str) -> Result<Vec<PositionValue>> {
-        let path = format!("/value?user={}", user);
+        let path = QueryBuilder::new().push("user", user).append_to("/value");
         self.http_client.get(&path, None).await
     }
 
@@ -59,17 +176,47 @@ impl DataClient {
         user: &str,
         params: Option<TradeQueryParams>,
     ) -> Result<Vec<Trade>> {
-        let mut path = format!("/trades?user={}", user);
-
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
-        }
-
-        println!("{}", path);
+        let path = trades_path(Some(user), &TradeParams::default(), params.as_ref());
+        self.http_client.get(&path, None).await
+    }
 
+    /// Get trades matching every set field of `params`
+    ///
+    /// Use `before`/`after` (Unix seconds) to walk back through history in
+    /// time windows.
+    ///
+    /// # Arguments
+    /// * `params` - Trade filters (id, market, asset_id, before, after, maker_address)
+    ///
+    /// # Returns
+    /// The trades matching the filters
+    pub async fn get_trades_with_params(&self, params: &TradeParams) -> Result<Vec<Trade>> {
+        let path = trades_path(None, params, None);
         self.http_client.get(&path, None).await
     }
 
+    /// Get one page of a user's trades
+    ///
+    /// Set [`TradeParams::cursor`] to the `next_cursor` of the previous page to walk
+    /// back through history. The returned cursor is `None` on the last page; a
+    /// plain list answer is returned as a single last page.
+    ///
+    /// # Arguments
+    /// * `user` - User wallet address to filter trades
+    /// * `params` - Trade filters and the cursor of the page to fetch
+    ///
+    /// # Returns
+    /// The trades of the page and the cursor of the next page
+    pub async fn get_trades_paginated(
+        &self,
+        user: &str,
+        params: TradeParams,
+    ) -> Result<PageResponse<Trade>> {
+        let path = trades_path(Some(user), &params, None);
+        let response: MaybePaginated<Trade> = self.http_client.get(&path, None).await?;
+        Ok(response.into_page())
+    }
+
     /// Get recent activity
     ///
     /// # Arguments
@@ -83,12 +230,35 @@ impl DataClient {
         user: &str,
         params: Option<ActivityQueryParams>,
     ) -> Result<Vec<Activity>> {
-        let mut path = format!("/activity?user={}", user);
+        let path = QueryBuilder::new()
+            .push("user", user)
+            .extend(
+                params
+                    .as_ref()
+                    .map(|p| p.to_query_params())
+                    .unwrap_or_default(),
+            )
+            .append_to("/activity");
 
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
-        }
+        self.http_client.get(&path, None).await
+    }
 
+    /// Get a user's activity restricted to the given types
+    ///
+    /// An empty `types` slice applies no filter.
+    ///
+    /// # Arguments
+    /// * `user` - User wallet address to filter activity
+    /// * `types` - Activity types to return (e.g. trades and redemptions only)
+    ///
+    /// # Returns
+    /// The user's activity events of the given types
+    pub async fn get_activity_filtered(
+        &self,
+        user: &str,
+        types: &[ActivityType],
+    ) -> Result<Vec<Activity>> {
+        let path = activity_path(user, types);
         self.http_client.get(&path, None).await
     }
 
@@ -100,7 +270,69 @@ impl DataClient {
     /// # Returns
     /// A list of closed positions for the user
     pub async fn get_closed_positions(&self, user: &str) -> Result<Vec<ClosedPosition>> {
-        let path = format!("/closed-positions?user={}", user);
+        let path = QueryBuilder::new()
+            .push("user", user)
+            .append_to("/closed-positions");
         self.http_client.get(&path, None).await
     }
 }
+
+/// Build the `/trades` request path from a user, trade filters and paging parameters
+fn trades_path(
+    user: Option<&str>,
+    params: &TradeParams,
+    query: Option<&TradeQueryParams>,
+) -> String {
+    QueryBuilder::new()
+        .push_opt("user", user)
+        .extend(params.to_query_params())
+        .extend(query.map(|q| q.to_query_params()).unwrap_or_default())
+        .append_to("/trades")
+}
+
+/// Build the `/activity` request path filtered by activity types
+fn activity_path(user: &str, types: &[ActivityType]) -> String {
+    let types = types
+        .iter()
+        .map(ActivityType::as_str)
+        .collect::<Vec<_>>()
+        .join(",");
+    QueryBuilder::new()
+        .push("user", user)
+        .push_opt("type", (!types.is_empty()).then_some(types))
+        .append_to("/activity")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trades_path() {
+        let params = TradeParams::new().market("0xabc").after(1_700_000_000);
+        assert_eq!(
+            trades_path(None, &params, None),
+            "/trades?after=1700000000&market=0xabc"
+        );
+
+        let params = TradeParams::new().before(1_700_000_000).cursor("MTA=");
+        assert_eq!(
+            trades_path(Some("0xuser"), &params, None),
+            "/trades?before=1700000000&next_cursor=MTA%3D&user=0xuser"
+        );
+
+        let query = TradeQueryParams::new().with_limit(10);
+        assert_eq!(
+            trades_path(Some("0xuser"), &TradeParams::default(), Some(&query)),
+            "/trades?limit=10&user=0xuser"
+        );
+    }
+    #[test]
+    fn test_activity_path() {
+        assert_eq!(
+            activity_path("0xuser", &[ActivityType::Trade, ActivityType::Redeem]),
+            "/activity?type=TRADE%2CREDEEM&user=0xuser"
+        );
+        assert_eq!(activity_path("0xuser", &[]), "/activity?user=0xuser");
+    }
+}
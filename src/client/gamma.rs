@@ -1,7 +1,15 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
-use crate::request::GammaMarketParams;
-use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag};
+use crate::request::{
+    encode_path_segment, GammaEventParams, GammaMarketParams, QueryBuilder, SearchParams,
+};
+use crate::types::{
+    deserialize_each, GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag, SearchResults,
+};
+use std::collections::HashMap;
+
+/// Page size used by paginated requests when no limit is given
+const DEFAULT_PAGE_SIZE: u32 = 100;
 
 /// Client for Gamma API - Market discovery and metadata
 ///
@@ -54,6 +62,15 @@ impl GammaClient {
         }
     }
 
+    /// Replace the underlying HTTP client
+    ///
+    /// Use this with a client built by [`HttpClient::builder`] to tune connection
+    /// pooling. The HTTP client's base URL is used for all requests.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
     /// Get markets with optional filtering and pagination
     ///
     /// # Arguments
@@ -96,6 +113,68 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get markets, skipping entries that fail to deserialize
+    ///
+    /// Same request as [`get_markets`](Self::get_markets), but each market is parsed
+    /// on its own so a single malformed entry does not fail the whole page.
+    ///
+    /// # Returns
+    /// The markets that parsed, and for every market that did not, its index in the
+    /// page, its raw JSON and the deserialization error
+    pub async fn get_markets_lenient(
+        &self,
+        params: Option<GammaMarketParams>,
+    ) -> Result<(Vec<GammaMarket>, Vec<(usize, serde_json::Value, Error)>)> {
+        let mut path = "/markets".to_string();
+        if let Some(p) = params {
+            path.push_str(&p.to_query_string());
+        }
+        let values: Vec<serde_json::Value> = self.http_client.get(&path, None).await?;
+        Ok(deserialize_each(values))
+    }
+
+    /// Get several markets by condition ID in a single request
+    ///
+    /// # Arguments
+    /// * `condition_ids` - The condition IDs of the markets to retrieve
+    ///
+    /// # Returns
+    /// The markets found, in the same order as `condition_ids`. IDs with no
+    /// matching market are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let markets = client.get_markets_by_ids(&["0x123...", "0x456..."]).await?;
+    /// println!("Found {} markets", markets.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_markets_by_ids(&self, condition_ids: &[&str]) -> Result<Vec<GammaMarket>> {
+        if condition_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let path = QueryBuilder::new()
+            .extend(condition_ids.iter().map(|id| ("condition_ids", id)))
+            .push("limit", condition_ids.len())
+            .append_to("/markets");
+        let markets: Vec<GammaMarket> = self.http_client.get(&path, None).await?;
+
+        let mut by_id: HashMap<String, GammaMarket> = markets
+            .into_iter()
+            .map(|market| (market.condition_id.clone(), market))
+            .collect();
+        Ok(condition_ids
+            .iter()
+            .filter_map(|id| by_id.remove(*id))
+            .collect())
+    }
+
     /// Get a specific market by condition ID
     ///
     /// # Arguments
@@ -119,10 +198,36 @@ impl GammaClient {
     /// # }
     /// ```
     pub async fn get_market(&self, condition_id: &str) -> Result<GammaMarket> {
-        let path = format!("/markets/{}", condition_id);
+        let path = format!("/markets/{}", encode_path_segment(condition_id));
         self.http_client.get(&path, None).await
     }
 
+    /// Get a market by its slug (e.g. `will-trump-win-2024`)
+    ///
+    /// # Arguments
+    /// * `slug` - The market slug, as found in polymarket.com URLs
+    ///
+    /// # Errors
+    /// Returns [`Error::NotFound`] unless exactly one market has this slug.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let market = client.get_market_by_slug("will-trump-win-2024").await?;
+    /// println!("Market: {}", market.question);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_market_by_slug(&self, slug: &str) -> Result<GammaMarket> {
+        let path = slug_path("/markets", slug);
+        let markets = self.http_client.get(&path, None).await?;
+        single_match(markets, "market", slug)
+    }
+
     /// Get all available tags
     ///
     /// Tags are used for categorizing and filtering markets. This endpoint returns
@@ -196,32 +301,94 @@ impl GammaClient {
     /// # }
     /// ```
     pub async fn get_market_by_id(&self, id: &str) -> Result<GammaMarket> {
-        let path = format!("/markets/{}", id);
+        let path = format!("/markets/{}", encode_path_segment(id));
         self.http_client.get(&path, None).await
     }
 
-    /// Get all events
+    /// Get events with optional filtering and pagination
+    ///
+    /// Events are collections of related markets. Without parameters, this returns
+    /// the first page of events as served by the API.
     ///
-    /// Events are collections of related markets. This endpoint returns
-    /// all events available in the Gamma API.
+    /// # Arguments
+    /// * `params` - Optional query parameters for filtering and pagination
     ///
     /// # Returns
-    /// A list of all events with their metadata
+    /// A list of events with their metadata
     ///
     /// # Example
     /// ```no_run
     /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::GammaEventParams;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> polymarket_rs::Result<()> {
     /// let client = GammaClient::new("https://gamma-api.polymarket.com");
-    /// let events = client.get_events().await?;
+    /// let params = GammaEventParams::new().with_active(true).with_limit(20);
+    /// let events = client.get_events(Some(params)).await?;
     /// println!("Found {} events", events.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_events(&self) -> Result<Vec<GammaEvent>> {
-        self.http_client.get("/events", None).await
+    pub async fn get_events(&self, params: Option<GammaEventParams>) -> Result<Vec<GammaEvent>> {
+        let mut path = "/events".to_string();
+        if let Some(p) = params {
+            path.push_str(&p.to_query_string());
+        }
+        self.http_client.get(&path, None).await
+    }
+
+    /// Get events with optional filtering and pagination
+    #[deprecated(note = "use `get_events`, which now takes the parameters")]
+    pub async fn get_events_with_params(
+        &self,
+        params: Option<GammaEventParams>,
+    ) -> Result<Vec<GammaEvent>> {
+        self.get_events(params).await
+    }
+
+    /// Get every event matching the filters, following offset-based pages
+    ///
+    /// Requests pages of `limit` events (100 if unset), starting at `offset` (0 if
+    /// unset), until a page comes back empty. Each page advances the offset by the
+    /// number of events it returned, so a server capping pages below `limit` does
+    /// not cut the results short.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filters; `limit` sets the page size
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::GammaEventParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let params = GammaEventParams::new().with_active(true).with_closed(false);
+    /// let events = client.get_events_paginated(Some(params)).await?;
+    /// println!("Found {} active events", events.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_events_paginated(
+        &self,
+        params: Option<GammaEventParams>,
+    ) -> Result<Vec<GammaEvent>> {
+        let mut params = params.unwrap_or_default();
+        let limit = params.limit.unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+        let mut offset = params.offset.unwrap_or(0);
+        let mut events = Vec::new();
+
+        loop {
+            params = params.with_limit(limit).with_offset(offset);
+            let page = self.get_events(Some(params.clone())).await?;
+            if page.is_empty() {
+                return Ok(events);
+            }
+            offset += page.len() as u32;
+            events.extend(page);
+        }
     }
 
     /// Get a specific event by its ID
@@ -245,10 +412,23 @@ impl GammaClient {
     /// # }
     /// ```
     pub async fn get_event_by_id(&self, id: &str) -> Result<GammaEvent> {
-        let path = format!("/events/{}", id);
+        let path = format!("/events/{}", encode_path_segment(id));
         self.http_client.get(&path, None).await
     }
 
+    /// Get an event by its slug
+    ///
+    /// # Arguments
+    /// * `slug` - The event slug, as found in polymarket.com URLs
+    ///
+    /// # Errors
+    /// Returns [`Error::NotFound`] unless exactly one event has this slug.
+    pub async fn get_event_by_slug(&self, slug: &str) -> Result<GammaEvent> {
+        let path = slug_path("/events", slug);
+        let events = self.http_client.get(&path, None).await?;
+        single_match(events, "event", slug)
+    }
+
     /// Get all series
     ///
     /// Series are groupings of related events and markets. This endpoint returns
@@ -294,7 +474,135 @@ impl GammaClient {
     /// # }
     /// ```
     pub async fn get_series_by_id(&self, id: &str) -> Result<GammaSeries> {
-        let path = format!("/series/{}", id);
+        let path = format!("/series/{}", encode_path_segment(id));
+        self.http_client.get(&path, None).await
+    }
+
+    /// Search markets, events and profiles matching a free-text query
+    ///
+    /// # Arguments
+    /// * `query` - The text to search for
+    /// * `params` - Optional per-type result limit and active-only filter
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::SearchParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let params = SearchParams::new().with_limit_per_type(5).with_active_only(true);
+    /// let results = client.search("bitcoin", Some(params)).await?;
+    /// for event in &results.events {
+    ///     println!("{}: {} markets", event.title, event.markets.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(&self, query: &str, params: Option<SearchParams>) -> Result<SearchResults> {
+        let path = format!(
+            "/public-search{}",
+            params.unwrap_or_default().to_query_string(query)
+        );
         self.http_client.get(&path, None).await
     }
 }
+
+/// Build a list request path filtered by slug
+fn slug_path(base_path: &str, slug: &str) -> String {
+    QueryBuilder::new().push("slug", slug).append_to(base_path)
+}
+
+/// Take the only item of a slug lookup, or fail with [`Error::NotFound`]
+fn single_match<T>(mut items: Vec<T>, kind: &str, slug: &str) -> Result<T> {
+    match items.len() {
+        1 => Ok(items.remove(0)),
+        0 => Err(Error::NotFound(format!("No {} with slug {}", kind, slug))),
+        count => Err(Error::NotFound(format!(
+            "Slug {} matches {} {}s, expected one",
+            slug, count, kind
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_slug_path() {
+        assert_eq!(
+            slug_path("/markets", "will-trump-win-2024"),
+            "/markets?slug=will-trump-win-2024"
+        );
+        assert_eq!(slug_path("/events", "a b/c"), "/events?slug=a%20b%2Fc");
+    }
+
+    #[test]
+    fn test_single_match() {
+        assert_eq!(single_match(vec![1], "market", "x").unwrap(), 1);
+        assert!(matches!(
+            single_match(Vec::<u32>::new(), "market", "x"),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            single_match(vec![1, 2], "event", "x"),
+            Err(Error::NotFound(msg)) if msg.contains("2 events")
+        ));
+    }
+
+    /// Serve 5 events, capping every page at 2 whatever `limit` asks for
+    async fn capped_events_server() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut tcp, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = vec![0; 8192];
+                    let n = tcp.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]);
+                    let offset: usize = request
+                        .split_once("offset=")
+                        .and_then(|(_, rest)| rest.split(['&', ' ']).next())
+                        .and_then(|offset| offset.parse().ok())
+                        .unwrap_or(0);
+
+                    let events: Vec<_> = (offset..5)
+                        .take(2)
+                        .map(|id| {
+                            serde_json::json!({
+                                "id": id.to_string(),
+                                "ticker": "t",
+                                "slug": "s",
+                                "title": "e",
+                                "markets": []
+                            })
+                        })
+                        .collect();
+                    let body = serde_json::Value::from(events).to_string();
+
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = tcp.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_events_paginated_follows_capped_pages() {
+        let client = GammaClient::new(capped_events_server().await);
+        let events = client.get_events_paginated(None).await.unwrap();
+
+        let ids: Vec<_> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["0", "1", "2", "3", "4"]);
+    }
+}
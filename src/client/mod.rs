@@ -8,4 +8,4 @@ pub use authenticated::AuthenticatedClient;
 pub use clob::ClobClient;
 pub use data::DataClient;
 pub use gamma::GammaClient;
-pub use trading::TradingClient;
+pub use trading::{OrderObserver, TradingClient};
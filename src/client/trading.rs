@@ -1,12 +1,83 @@
-use crate::error::Result;
+use crate::clock::Clock;
+use crate::error::{Error, Result};
 use crate::http::{create_l2_headers, HttpClient};
-use crate::orders::{calculate_market_price, OrderBuilder};
+use crate::orders::{calculate_market_price, calculate_shares_for_usdc, OrderBuilder};
+use crate::request::{encode_path_segment, paginate, Paginated, QueryBuilder, INITIAL_CURSOR};
 use crate::signing::EthSigner;
 use crate::types::{
-    ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OpenOrder,
-    OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderType,
-    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    Amount, ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, Market,
+    MarketOrderArgs, OpenOrder, OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary,
+    OrderId, OrderType, Position, PostOrder, PostOrderArgs, PostOrderResponse, Reconciliation,
+    Side, SignedOrderRequest, TradeParams,
 };
+use futures_timer::Delay;
+use futures_util::TryStreamExt;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+/// Check a signed order's expiration against the rules of its order type
+fn validate_order_expiration(
+    order: &SignedOrderRequest,
+    order_type: OrderType,
+    clock: &dyn Clock,
+) -> Result<()> {
+    let expiration = order.expiration.parse::<u64>().map_err(|e| {
+        Error::InvalidOrder(format!("Invalid expiration {}: {}", order.expiration, e))
+    })?;
+    order_type.validate_expiration(expiration, clock.unix_time_secs()?)
+}
+
+/// Turn an order the exchange refused into a typed error
+///
+/// Rejections arrive either as a 400 response with an `{"error": "..."}` body or as
+/// a successful response with `success: false`.
+fn map_order_rejection(result: Result<PostOrderResponse>) -> Result<PostOrderResponse> {
+    match result {
+        Ok(response) => match response.rejection() {
+            Some(e) => Err(e),
+            None => Ok(response),
+        },
+        Err(Error::Api {
+            status: 400,
+            message,
+        }) => {
+            let reason = serde_json::from_str::<serde_json::Value>(&message)
+                .ok()
+                .and_then(|body| body.get("error")?.as_str().map(str::to_string))
+                .unwrap_or(message);
+            Err(Error::from_order_rejection(reason))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Observer notified of every order submitted by a [`TradingClient`]
+///
+/// `on_submit` is called synchronously with the exact payload before it is sent,
+/// and `on_response` is called once the server has answered (or the request failed).
+/// Any `Fn(&PostOrder) + Send + Sync` closure can be used as an observer that only
+/// records submissions.
+pub trait OrderObserver: Send + Sync {
+    /// Called before an order is submitted
+    fn on_submit(&self, order: &PostOrder);
+
+    /// Called with the outcome of a submitted order
+    fn on_response(
+        &self,
+        _order: &PostOrder,
+        _response: std::result::Result<&PostOrderResponse, &Error>,
+    ) {
+    }
+}
+
+impl<F> OrderObserver for F
+where
+    F: Fn(&PostOrder) + Send + Sync,
+{
+    fn on_submit(&self, order: &PostOrder) {
+        self(order)
+    }
+}
 
 /// Client for trading operations
 ///
@@ -18,9 +89,13 @@ pub struct TradingClient {
     chain_id: u64,
     api_creds: ApiCreds,
     order_builder: OrderBuilder,
+    order_observer: Option<Box<dyn OrderObserver>>,
 }
 
 impl TradingClient {
+    /// Delay between open order checks in [`cancel_all_and_confirm`](Self::cancel_all_and_confirm)
+    pub const CANCEL_CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
     /// Create a new TradingClient
     ///
     /// # Arguments
@@ -42,9 +117,39 @@ impl TradingClient {
             chain_id,
             api_creds,
             order_builder,
+            order_observer: None,
         }
     }
 
+    /// Replace the underlying HTTP client
+    ///
+    /// Use this with a client built by [`HttpClient::builder`] to tune connection
+    /// pooling. The HTTP client's base URL is used for all requests.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Set an observer notified of every submitted order
+    ///
+    /// The observer sees each [`PostOrder`] payload before it is sent, and the
+    /// matching response afterwards. This is useful for audit logging, as no order
+    /// can be submitted through this client without being recorded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use polymarket_rs::client::TradingClient;
+    /// # fn example(trading_client: TradingClient) {
+    /// let trading_client = trading_client.with_order_observer(|order: &polymarket_rs::types::PostOrder| {
+    ///     println!("Submitting order: {:?}", order);
+    /// });
+    /// # }
+    /// ```
+    pub fn with_order_observer(mut self, observer: impl OrderObserver + 'static) -> Self {
+        self.order_observer = Some(Box::new(observer));
+        self
+    }
+
     /// Create a limit order (local operation, not posted)
     ///
     /// # Arguments
@@ -91,7 +196,12 @@ impl TradingClient {
         };
 
         // Calculate market price from order book
-        let price = calculate_market_price(book_side, order_args.amount, order_args.side)?;
+        let price = match order_args.amount {
+            Amount::Shares(shares) => calculate_market_price(book_side, shares, order_args.side)?,
+            Amount::Collateral(collateral) => {
+                calculate_shares_for_usdc(book_side, collateral, order_args.side)?.1
+            }
+        };
 
         self.order_builder
             .create_market_order(self.chain_id, order_args, price, extras, options)
@@ -102,24 +212,46 @@ impl TradingClient {
     /// # Arguments
     /// * `order` - The signed order to post
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    ///
+    /// # Errors
+    /// Orders refused by the exchange are returned as typed errors such as
+    /// [`Error::InsufficientBalance`] or [`Error::FokNotFilled`], falling back to
+    /// [`Error::OrderRejected`]. See [`Error::from_order_rejection`].
     pub async fn post_order(
         &self,
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
+        validate_order_expiration(&order, order_type, self.order_builder.clock())?;
+
         let owner = self.api_creds.api_key.clone();
         let post_order = PostOrder::new(order, owner, order_type);
 
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "POST",
-            "/order",
-            Some(&post_order),
-        )?;
-        self.http_client
-            .post("/order", &post_order, Some(headers))
-            .await
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "POST",
+                "/order",
+                Some(&post_order),
+            )
+        };
+
+        if let Some(observer) = &self.order_observer {
+            observer.on_submit(&post_order);
+        }
+
+        let result = map_order_rejection(
+            self.http_client
+                .post_signed("/order", &post_order, headers)
+                .await,
+        );
+
+        if let Some(observer) = &self.order_observer {
+            observer.on_response(&post_order, result.as_ref());
+        }
+
+        result
     }
 
     /// Post multiple orders to the exchange
@@ -140,6 +272,10 @@ impl TradingClient {
     /// # }
     /// ```
     pub async fn post_orders(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
+        for arg in orders {
+            validate_order_expiration(&arg.order, arg.order_type, self.order_builder.clock())?;
+        }
+
         let owner = self.api_creds.api_key.clone();
 
         // Build array of PostOrder structs
@@ -148,17 +284,43 @@ impl TradingClient {
             .map(|arg| PostOrder::new(arg.order.clone(), owner.clone(), arg.order_type))
             .collect();
 
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "POST",
-            "/orders",
-            Some(&post_orders),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "POST",
+                "/orders",
+                Some(&post_orders),
+            )
+        };
 
-        self.http_client
-            .post("/orders", &post_orders, Some(headers))
-            .await
+        if let Some(observer) = &self.order_observer {
+            for post_order in &post_orders {
+                observer.on_submit(post_order);
+            }
+        }
+
+        let result: Result<Vec<PostOrderResponse>> = self
+            .http_client
+            .post_signed("/orders", &post_orders, headers)
+            .await;
+
+        if let Some(observer) = &self.order_observer {
+            match &result {
+                Ok(responses) => {
+                    for (post_order, response) in post_orders.iter().zip(responses) {
+                        observer.on_response(post_order, Ok(response));
+                    }
+                }
+                Err(e) => {
+                    for post_order in &post_orders {
+                        observer.on_response(post_order, Err(e));
+                    }
+                }
+            }
+        }
+
+        result
     }
 
     /// Create and post an order in one step
@@ -183,6 +345,129 @@ impl TradingClient {
         self.post_order(order, order_type).await
     }
 
+    /// Create and post an order, failing fast if the market is not accepting orders
+    ///
+    /// Same as [`create_and_post_order`](Self::create_and_post_order), but checks
+    /// `market.accepting_orders` before signing so paused or closed markets return
+    /// [`Error::MarketNotAcceptingOrders`](crate::Error::MarketNotAcceptingOrders)
+    /// without a signing round-trip or a guaranteed server rejection.
+    ///
+    /// # Arguments
+    /// * `market` - The market the order is placed on
+    /// * `order_args` - Order arguments (token_id, price, size, side)
+    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
+    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    pub async fn create_and_post_order_for_market(
+        &self,
+        market: &Market,
+        order_args: &OrderArgs,
+        expiration: Option<u64>,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+        order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        market.ensure_accepting_orders()?;
+        self.create_and_post_order(order_args, expiration, extras, options, order_type)
+            .await
+    }
+
+    /// Place a limit order on a market from human inputs
+    ///
+    /// Resolves the token from the outcome name, takes the tick size and neg-risk
+    /// flag from `market`, then signs and posts the order. Checks that the market
+    /// is accepting orders first, like
+    /// [`create_and_post_order_for_market`](Self::create_and_post_order_for_market).
+    ///
+    /// Orders are placed without an expiration, so [`OrderType::Gtd`] is rejected;
+    /// use [`create_and_post_order`](Self::create_and_post_order) for those.
+    ///
+    /// # Arguments
+    /// * `market` - The market to trade
+    /// * `outcome` - The outcome name, matched case-insensitively (e.g. "Yes")
+    /// * `side` - Buy or sell
+    /// * `price` - Limit price
+    /// * `size` - Number of shares
+    /// * `order_type` - The order type (GTC, FOK, FAK)
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] if the market has no such outcome.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use polymarket_rs::client::TradingClient;
+    /// # use polymarket_rs::types::Market;
+    /// # use polymarket_rs::{OrderType, Side};
+    /// # use rust_decimal_macros::dec;
+    /// # async fn example(client: &TradingClient, market: &Market) -> polymarket_rs::Result<()> {
+    /// let response = client
+    ///     .quick_order(market, "Yes", Side::Buy, dec!(0.42), dec!(10), OrderType::Gtc)
+    ///     .await?;
+    /// println!("Order ID: {}", response.order_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quick_order(
+        &self,
+        market: &Market,
+        outcome: &str,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+        order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        let token = market.token_for_outcome(outcome).ok_or_else(|| {
+            Error::InvalidParameter(format!(
+                "Market {} has no outcome {:?}",
+                market.condition_id, outcome
+            ))
+        })?;
+
+        let order_args = OrderArgs::new(&token.token_id, price, size, side);
+        let options = CreateOrderOptions::new()
+            .tick_size(market.minimum_tick_size)
+            .neg_risk(market.neg_risk);
+
+        self.create_and_post_order_for_market(market, &order_args, None, None, options, order_type)
+            .await
+    }
+
+    /// Close a position by selling all of its shares at market
+    ///
+    /// Fetches the current order book for the position's asset, builds a market
+    /// sell for the full position size and posts it as a FOK order.
+    ///
+    /// # Arguments
+    /// * `position` - The position to close
+    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] if the position is redeemable: the market has
+    /// resolved and the shares should be redeemed instead of traded.
+    pub async fn close_position(
+        &self,
+        position: &Position,
+        options: CreateOrderOptions,
+    ) -> Result<PostOrderResponse> {
+        if position.redeemable {
+            return Err(Error::InvalidOrder(format!(
+                "Position in {} is redeemable; redeem it instead of selling",
+                position.condition_id
+            )));
+        }
+
+        let order_args = position.close_order_args();
+        let path = QueryBuilder::new()
+            .push("token_id", &order_args.token_id)
+            .append_to("/book");
+        let mut order_book: OrderBookSummary = self.http_client.get(&path, None).await?;
+        order_book.normalize();
+
+        let order = self.create_market_order(&order_args, &order_book, None, options)?;
+        self.post_order(order, OrderType::Fok).await
+    }
+
     /// Get open orders (L2 authentication required)
     ///
     /// # Arguments
@@ -192,33 +477,63 @@ impl TradingClient {
         // Query parameters are added to the URL after signing
         let base_path = "/data/orders";
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
+            || create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None);
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let request_path = QueryBuilder::new()
+            .extend(params.to_query_params())
+            .append_to(base_path);
+
+        self.http_client.get_signed(&request_path, headers).await
+    }
+
+    /// Reconcile expected open orders with the server state
+    ///
+    /// Fetches every open order (following pagination) and diffs it against the
+    /// orders the caller believes are open. Useful after a restart or reconnect.
+    ///
+    /// # Arguments
+    /// * `expected` - IDs of the orders assumed to be open
+    pub async fn reconcile_orders(&self, expected: &[OrderId]) -> Result<Reconciliation> {
+        let open_orders = self.get_all_open_orders().await?;
+        Ok(Reconciliation::new(expected, open_orders))
+    }
+
+    /// Fetch every open order, following pagination
+    async fn get_all_open_orders(&self) -> Result<Vec<OpenOrder>> {
+        let base_path = "/data/orders";
+
+        paginate(INITIAL_CURSOR, |pagination| async move {
+            let headers = || {
+                create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)
+            };
+            let request_path = QueryBuilder::new()
+                .extend(pagination.to_query_params())
+                .append_to(base_path);
 
-        self.http_client.get(&request_path, Some(headers)).await
+            let response: OpenOrdersResponse =
+                self.http_client.get_signed(&request_path, headers).await?;
+            Ok(response.into_page())
+        })
+        .try_collect()
+        .await
     }
 
     /// Get a specific order by ID
+    ///
+    /// # Errors
+    /// Returns [`Error::NotFound`] if the server has no order with this ID.
     pub async fn get_order(&self, order_id: &OrderId) -> Result<OpenOrder> {
-        let path = format!("/data/order/{}", order_id.as_str());
+        let path = format!("/data/order/{}", encode_path_segment(order_id.as_str()));
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", &path, None)?;
-        self.http_client.get(&path, Some(headers)).await
+            || create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", &path, None);
+
+        // Unknown orders come back either as a 404 or as a `null` body
+        let order: Option<OpenOrder> = match self.http_client.get_signed(&path, headers).await {
+            Err(Error::Api { status: 404, .. }) => None,
+            result => result?,
+        };
+        order.ok_or_else(|| Error::NotFound(format!("order {}", order_id)))
     }
 
     /// Cancel a specific order
@@ -227,15 +542,17 @@ impl TradingClient {
     /// * `order_id` - The ID of the order to cancel
     pub async fn cancel(&self, order_id: &OrderId) -> Result<CancelOrdersResponse> {
         let body = serde_json::json!({ "orderID": order_id.as_str() });
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/order",
-            Some(&body),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "DELETE",
+                "/order",
+                Some(&body),
+            )
+        };
         self.http_client
-            .delete_with_body("/order", &body, Some(headers))
+            .delete_with_body_signed("/order", &body, headers)
             .await
     }
 
@@ -246,33 +563,74 @@ impl TradingClient {
     pub async fn cancel_orders(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/orders",
-            Some(&body),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "DELETE",
+                "/orders",
+                Some(&body),
+            )
+        };
         self.http_client
-            .delete_with_body("/orders", &body, Some(headers))
+            .delete_with_body_signed("/orders", &body, headers)
             .await
     }
 
     /// Cancel all orders
     pub async fn cancel_all(&self) -> Result<CancelOrdersResponse> {
         let body = serde_json::json!({});
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/cancel-all",
-            Some(&body),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "DELETE",
+                "/cancel-all",
+                Some(&body),
+            )
+        };
         self.http_client
-            .delete_with_body("/cancel-all", &body, Some(headers))
+            .delete_with_body_signed("/cancel-all", &body, headers)
             .await
     }
 
+    /// Cancel all orders and wait until none are reported open
+    ///
+    /// Issues [`cancel_all`](Self::cancel_all), then polls the open orders every
+    /// [`CANCEL_CONFIRM_POLL_INTERVAL`](Self::CANCEL_CONFIRM_POLL_INTERVAL) until the
+    /// list is empty. Use it before shutting down a bot to be sure nothing is left resting.
+    ///
+    /// `max_wait` bounds the total delay between polls, not the time spent in
+    /// requests, so it does not depend on the order builder's clock.
+    ///
+    /// # Errors
+    /// Returns [`Error::CancelNotConfirmed`] with the IDs of the orders still open
+    /// once `max_wait` has elapsed.
+    pub async fn cancel_all_and_confirm(&self, max_wait: Duration) -> Result<()> {
+        self.cancel_all().await?;
+
+        let mut waited = Duration::ZERO;
+        loop {
+            let open_orders = self.get_all_open_orders().await?;
+            if open_orders.is_empty() {
+                return Ok(());
+            }
+
+            if waited >= max_wait {
+                return Err(Error::CancelNotConfirmed {
+                    order_ids: open_orders
+                        .into_iter()
+                        .map(|order| order.id.to_string())
+                        .collect(),
+                });
+            }
+
+            let delay = Self::CANCEL_CONFIRM_POLL_INTERVAL.min(max_wait - waited);
+            Delay::new(delay).await;
+            waited += delay;
+        }
+    }
+
     /// Cancel all orders for a specific market and/or asset
     ///
     /// # Arguments
@@ -289,15 +647,17 @@ impl TradingClient {
             "asset_id": asset_id.unwrap_or("")
         });
 
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/cancel-market-orders",
-            Some(&body),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "DELETE",
+                "/cancel-market-orders",
+                Some(&body),
+            )
+        };
         self.http_client
-            .delete_with_body("/cancel-market-orders", &body, Some(headers))
+            .delete_with_body_signed("/cancel-market-orders", &body, headers)
             .await
     }
 
@@ -309,25 +669,14 @@ impl TradingClient {
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/data/trades";
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
+            || create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None);
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let request_path = QueryBuilder::new()
+            .extend(params.to_query_params())
+            .append_to(base_path);
 
-        self.http_client.get(&request_path, Some(headers)).await
+        self.http_client.get_signed(&request_path, headers).await
     }
 
     /// Check if an order is scoring
@@ -335,27 +684,102 @@ impl TradingClient {
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/order-scoring";
         let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
+            || create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None);
 
         // Build the full request path WITH query parameters
-        let request_path = format!("{}?id={}", base_path, order_id.as_str());
+        let request_path = QueryBuilder::new()
+            .push("id", order_id.as_str())
+            .append_to(base_path);
 
-        self.http_client.get(&request_path, Some(headers)).await
+        self.http_client.get_signed(&request_path, headers).await
     }
 
     /// Check if multiple orders are scoring
     pub async fn are_orders_scoring(&self, order_ids: &[OrderId]) -> Result<serde_json::Value> {
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "POST",
-            "/orders-scoring",
-            Some(&body),
-        )?;
+        let headers = || {
+            create_l2_headers(
+                &self.signer,
+                &self.api_creds,
+                "POST",
+                "/orders-scoring",
+                Some(&body),
+            )
+        };
         self.http_client
-            .post("/orders-scoring", &body, Some(headers))
+            .post_signed("/orders-scoring", &body, headers)
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::types::open_order_json;
+    use alloy_signer_local::PrivateKeySigner;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serve HTTP requests, answering cancels with an empty result and order
+    /// queries with one order that never goes away
+    async fn mock_server() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut tcp, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = vec![0; 8192];
+                    let n = tcp.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]);
+
+                    let body = if request.starts_with("DELETE") {
+                        serde_json::json!({"canceled": [], "not_canceled": {}})
+                    } else {
+                        serde_json::json!({
+                            "data": [open_order_json("0xstuck")],
+                            "limit": 100,
+                            "count": 1,
+                            "next_cursor": "LTE="
+                        })
+                    }
+                    .to_string();
+
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = tcp.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_and_confirm_times_out_with_fixed_clock() {
+        let signer = PrivateKeySigner::random();
+        let clock = FixedClock::from_unix_secs(1_700_000_000).unwrap();
+        let order_builder = OrderBuilder::new(signer.clone(), None, None).with_clock(clock);
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "c2VjcmV0".to_string(),
+            "passphrase".to_string(),
+        );
+        let client = TradingClient::new(mock_server().await, signer, 137, creds, order_builder);
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.cancel_all_and_confirm(Duration::from_millis(100)),
+        )
+        .await
+        .expect("the wait should not depend on the fixed clock");
+        assert!(matches!(
+            result,
+            Err(Error::CancelNotConfirmed { order_ids }) if order_ids == vec!["0xstuck"]
+        ));
+    }
+}
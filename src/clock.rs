@@ -0,0 +1,64 @@
+//! Time sources for time-dependent logic.
+//!
+//! Order building, expiration checks and market timing read the current time
+//! through the [`Clock`] trait. [`SystemClock`] is used by default; tests can
+//! inject a [`FixedClock`] (or their own implementation) for deterministic results.
+
+use chrono::{DateTime, Utc};
+
+use crate::error::Result;
+use crate::utils::time::datetime_to_unix_secs;
+
+/// Source of the current time
+pub trait Clock: Send + Sync {
+    /// Get the current time
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Get the current Unix timestamp in seconds
+    fn unix_time_secs(&self) -> Result<u64> {
+        datetime_to_unix_secs(&self.now())
+    }
+}
+
+/// Clock reading the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always returns the same time
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::clock::{Clock, FixedClock};
+///
+/// let clock = FixedClock::from_unix_secs(1_700_000_000).unwrap();
+/// assert_eq!(clock.unix_time_secs().unwrap(), 1_700_000_000);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    /// Create a clock fixed at the given time
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self(now)
+    }
+
+    /// Create a clock fixed at the given Unix timestamp (seconds)
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    pub fn from_unix_secs(secs: i64) -> Option<Self> {
+        DateTime::from_timestamp(secs, 0).map(Self)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -79,6 +79,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_amoy_testnet_standard() {
+        let config = get_contract_config(chains::POLYGON_AMOY_TESTNET, false).unwrap();
+        assert_eq!(
+            config.exchange,
+            "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"
+        );
+        assert_eq!(
+            config.collateral,
+            "0x9c4e1703476e875070ee25b56a58b008cfb8fa78"
+        );
+    }
+
+    #[test]
+    fn test_amoy_testnet_neg_risk() {
+        let config = get_contract_config(chains::POLYGON_AMOY_TESTNET, true).unwrap();
+        assert_eq!(
+            config.exchange,
+            "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296"
+        );
+        assert_eq!(
+            config.conditional_tokens,
+            "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB"
+        );
+    }
+
     #[test]
     fn test_unsupported_chain() {
         let result = get_contract_config(999, false);
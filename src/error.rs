@@ -42,14 +42,100 @@ pub enum Error {
     /// WebSocket connection closed
     ConnectionClosed,
 
+    /// No pong answered a keepalive ping in time
+    KeepaliveTimeout,
+
     /// Reconnection failed after multiple attempts
+    ///
+    /// No longer returned; reconnecting streams report [`Error::ReconnectExhausted`].
+    #[deprecated(note = "use ReconnectExhausted")]
     ReconnectFailed {
         attempts: u32,
         last_error: String,
     },
+
+    /// Reconnection stopped after reaching the maximum number of attempts
+    ReconnectExhausted { attempts: u32 },
+
+    /// Market is not currently accepting orders
+    MarketNotAcceptingOrders(String),
+
+    /// Requested resource does not exist
+    NotFound(String),
+
+    /// Subscribed assets produced no data before the timeout
+    SubscriptionTimeout { assets: Vec<String> },
+
+    /// Arithmetic overflowed while computing an amount
+    ArithmeticOverflow(String),
+
+    /// Order rejected: not enough collateral or shares
+    InsufficientBalance(String),
+
+    /// Order rejected: the exchange is not approved to spend enough collateral or shares
+    InsufficientAllowance(String),
+
+    /// Order rejected: the market is closed or not ready for orders
+    MarketClosed(String),
+
+    /// Order rejected: invalid size, price or amount precision
+    InvalidAmount(String),
+
+    /// Order rejected: a fill-or-kill order could not be fully filled
+    FokNotFilled(String),
+
+    /// Order rejected for a reason not covered by a more specific variant
+    OrderRejected(String),
+
+    /// Operation only supports binary (two-outcome) markets
+    UnsupportedMarket(String),
+
+    /// Orders were still open when waiting for a cancellation timed out
+    CancelNotConfirmed { order_ids: Vec<String> },
+
+    /// Building one order of a batch failed
+    BatchOrderFailed { index: usize, error: Box<Error> },
+}
+
+impl Error {
+    /// Classify an order rejection message returned by the exchange
+    ///
+    /// Matches both the documented error codes (e.g. `FOK_ORDER_NOT_FILLED_ERROR`)
+    /// and the human readable messages. The exchange reports a shortfall as
+    /// `not enough balance / allowance` without saying which one, so that message
+    /// maps to [`Error::InsufficientBalance`]; [`Error::InsufficientAllowance`] is
+    /// only returned when the message mentions the allowance alone.
+    pub fn from_order_rejection(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let lower = message.to_lowercase();
+
+        if lower.contains("fok_order_not_filled") || lower.contains("fully filled or killed") {
+            Error::FokNotFilled(message)
+        } else if lower.contains("balance") || lower.contains("not_enough_balance") {
+            Error::InsufficientBalance(message)
+        } else if lower.contains("allowance") {
+            Error::InsufficientAllowance(message)
+        } else if lower.contains("market_not_ready")
+            || lower.contains("not yet ready")
+            || lower.contains("market is closed")
+            || lower.contains("closed market")
+        {
+            Error::MarketClosed(message)
+        } else if lower.contains("invalid amount")
+            || lower.contains("min_size")
+            || lower.contains("min_tick_size")
+            || lower.contains("lower than the minimum")
+            || lower.contains("accuracy")
+        {
+            Error::InvalidAmount(message)
+        } else {
+            Error::OrderRejected(message)
+        }
+    }
 }
 
 impl fmt::Display for Error {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Http(e) => write!(f, "HTTP error: {}", e),
@@ -66,6 +152,7 @@ impl fmt::Display for Error {
             Error::MissingField(field) => write!(f, "Missing required field: {}", field),
             Error::WebSocket(msg) => write!(f, "WebSocket error: {}", msg),
             Error::ConnectionClosed => write!(f, "WebSocket connection closed"),
+            Error::KeepaliveTimeout => write!(f, "WebSocket keepalive timed out waiting for pong"),
             Error::ReconnectFailed {
                 attempts,
                 last_error,
@@ -74,6 +161,34 @@ impl fmt::Display for Error {
                 "Reconnection failed after {} attempts: {}",
                 attempts, last_error
             ),
+            Error::ReconnectExhausted { attempts } => {
+                write!(f, "Reconnection gave up after {} attempts", attempts)
+            }
+            Error::MarketNotAcceptingOrders(condition_id) => {
+                write!(f, "Market {} is not accepting orders", condition_id)
+            }
+            Error::NotFound(msg) => write!(f, "Not found: {}", msg),
+            Error::SubscriptionTimeout { assets } => write!(
+                f,
+                "Subscription timed out waiting for data on assets: {}",
+                assets.join(", ")
+            ),
+            Error::ArithmeticOverflow(msg) => write!(f, "Arithmetic overflow: {}", msg),
+            Error::InsufficientBalance(msg) => write!(f, "Insufficient balance: {}", msg),
+            Error::InsufficientAllowance(msg) => write!(f, "Insufficient allowance: {}", msg),
+            Error::MarketClosed(msg) => write!(f, "Market closed: {}", msg),
+            Error::InvalidAmount(msg) => write!(f, "Invalid amount: {}", msg),
+            Error::FokNotFilled(msg) => write!(f, "FOK order not filled: {}", msg),
+            Error::OrderRejected(msg) => write!(f, "Order rejected: {}", msg),
+            Error::UnsupportedMarket(msg) => write!(f, "Unsupported market: {}", msg),
+            Error::CancelNotConfirmed { order_ids } => write!(
+                f,
+                "Orders still open after cancellation: {}",
+                order_ids.join(", ")
+            ),
+            Error::BatchOrderFailed { index, error } => {
+                write!(f, "Order {} in batch failed: {}", index, error)
+            }
         }
     }
 }
@@ -84,6 +199,7 @@ impl std::error::Error for Error {
             Error::Http(e) => Some(e),
             Error::Json(e) => Some(e),
             Error::Decimal(e) => Some(e),
+            Error::BatchOrderFailed { error, .. } => Some(error.as_ref()),
             _ => None,
         }
     }
@@ -113,8 +229,44 @@ impl From<alloy_signer::Error> for Error {
     }
 }
 
+#[cfg(feature = "websocket")]
 impl From<tokio_tungstenite::tungstenite::Error> for Error {
     fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
         Error::WebSocket(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_order_rejection() {
+        assert!(matches!(
+            Error::from_order_rejection("not enough balance / allowance"),
+            Error::InsufficientBalance(_)
+        ));
+        assert!(matches!(
+            Error::from_order_rejection("insufficient allowance for exchange"),
+            Error::InsufficientAllowance(_)
+        ));
+        assert!(matches!(
+            Error::from_order_rejection(
+                "order couldn't be fully filled. FOK orders are fully filled or killed."
+            ),
+            Error::FokNotFilled(_)
+        ));
+        assert!(matches!(
+            Error::from_order_rejection("the market is not yet ready to process new orders"),
+            Error::MarketClosed(_)
+        ));
+        assert!(matches!(
+            Error::from_order_rejection("INVALID_ORDER_MIN_SIZE"),
+            Error::InvalidAmount(_)
+        ));
+        assert!(matches!(
+            Error::from_order_rejection("something unexpected"),
+            Error::OrderRejected(msg) if msg == "something unexpected"
+        ));
+    }
+}
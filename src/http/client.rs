@@ -1,14 +1,113 @@
 use crate::error::{Error, Result};
-use reqwest::{Client, Response};
+use reqwest::{Client, RequestBuilder, Response};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::headers::Headers;
+use super::RateLimiter;
 
 /// HTTP client wrapper for making API requests
+///
+/// Use [`HttpClient::builder`] to tune the underlying connection pool and pass
+/// the result to a client's `with_http_client` method.
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
     base_url: String,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+/// Builder for an [`HttpClient`] with custom connection pool settings
+///
+/// Unset options keep reqwest's defaults. Connection pool options are ignored
+/// on `wasm32`, where requests go through the browser's fetch API.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::{ClobClient, HttpClient};
+/// use std::time::Duration;
+///
+/// # fn main() -> polymarket_rs::Result<()> {
+/// let http_client = HttpClient::builder()
+///     .pool_max_idle_per_host(32)
+///     .pool_idle_timeout(Duration::from_secs(90))
+///     .tcp_keepalive(Duration::from_secs(30))
+///     .build("https://clob.polymarket.com")?;
+///
+/// let client = ClobClient::new("https://clob.polymarket.com").with_http_client(http_client);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HttpClientBuilder {
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl HttpClientBuilder {
+    /// Set the maximum number of idle connections kept per host
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set how long an idle connection is kept in the pool
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable TCP keepalive with the given interval
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Throttle every request through a rate limiter
+    ///
+    /// Pass clones of the same `Arc` to several clients to share one budget.
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Build the HTTP client for the given base URL
+    pub fn build(self, base_url: impl Into<String>) -> Result<HttpClient> {
+        let rate_limiter = self.rate_limiter.clone();
+        Ok(HttpClient {
+            client: self.client_builder().build()?,
+            base_url: base_url.into(),
+            rate_limiter,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn client_builder(self) -> reqwest::ClientBuilder {
+        let mut builder = Client::builder();
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+
+        builder
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn client_builder(self) -> reqwest::ClientBuilder {
+        Client::builder()
+    }
 }
 
 impl HttpClient {
@@ -16,6 +115,19 @@ impl HttpClient {
         Self {
             client: Client::new(),
             base_url: base_url.into(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Create a builder for tuning the connection pool
+    pub fn builder() -> HttpClientBuilder {
+        HttpClientBuilder::default()
+    }
+
+    /// Wait for the rate limiter, if one is configured
+    async fn throttle(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
         }
     }
 
@@ -24,17 +136,24 @@ impl HttpClient {
     where
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.get(&url);
-
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
+        let request = self.client.get(self.url(path));
+        self.send(request, || Ok(headers)).await
+    }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Make a GET request with headers created once the rate limiter lets it through
+    ///
+    /// Use this for timestamped authentication headers, which could go stale while
+    /// waiting for the rate limiter if they were created beforehand.
+    pub async fn get_signed<T>(
+        &self,
+        path: &str,
+        headers: impl FnOnce() -> Result<Headers>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let request = self.client.get(self.url(path));
+        self.send(request, || headers().map(Some)).await
     }
 
     /// Make a POST request with JSON body
@@ -48,17 +167,24 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.post(&url).json(body);
-
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
+        let request = self.client.post(self.url(path)).json(body);
+        self.send(request, || Ok(headers)).await
+    }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Make a POST request with JSON body and headers created once the rate
+    /// limiter lets it through
+    pub async fn post_signed<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: impl FnOnce() -> Result<Headers>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.client.post(self.url(path)).json(body);
+        self.send(request, || headers().map(Some)).await
     }
 
     /// Make a DELETE request with optional JSON body
@@ -66,17 +192,21 @@ impl HttpClient {
     where
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.delete(&url);
-
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
+        let request = self.client.delete(self.url(path));
+        self.send(request, || Ok(headers)).await
+    }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Make a DELETE request with headers created once the rate limiter lets it through
+    pub async fn delete_signed<T>(
+        &self,
+        path: &str,
+        headers: impl FnOnce() -> Result<Headers>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let request = self.client.delete(self.url(path));
+        self.send(request, || headers().map(Some)).await
     }
 
     /// Make a DELETE request with JSON body
@@ -90,10 +220,42 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.delete(&url).json(body);
+        let request = self.client.delete(self.url(path)).json(body);
+        self.send(request, || Ok(headers)).await
+    }
+
+    /// Make a DELETE request with JSON body and headers created once the rate
+    /// limiter lets it through
+    pub async fn delete_with_body_signed<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: impl FnOnce() -> Result<Headers>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.client.delete(self.url(path)).json(body);
+        self.send(request, || headers().map(Some)).await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
 
-        if let Some(headers) = headers {
+    /// Wait for the rate limiter, then add the headers and send the request
+    async fn send<'h, T>(
+        &self,
+        mut request: RequestBuilder,
+        headers: impl FnOnce() -> Result<Option<HashMap<&'h str, String>>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.throttle().await;
+
+        if let Some(headers) = headers()? {
             for (key, value) in headers {
                 request = request.header(key, value);
             }
@@ -125,3 +287,34 @@ impl HttpClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_signed_headers_created_after_throttle() {
+        let limiter = Arc::new(RateLimiter::new(1, Duration::from_millis(200)).unwrap());
+
+        // Nothing listens on this port, so the request itself fails
+        let client = HttpClient::builder()
+            .rate_limiter(limiter.clone())
+            .build("http://127.0.0.1:1")
+            .unwrap();
+
+        let start = Instant::now();
+        assert!(limiter.try_acquire());
+        let signed_after = Mutex::new(None);
+        let _ = client
+            .get_signed::<serde_json::Value>("/", || {
+                *signed_after.lock().unwrap() = Some(start.elapsed());
+                Ok(Headers::new())
+            })
+            .await;
+
+        let signed_after = signed_after.into_inner().unwrap().unwrap();
+        assert!(signed_after >= Duration::from_millis(150));
+    }
+}
@@ -1,5 +1,7 @@
 mod client;
 mod headers;
+mod rate_limit;
 
-pub use client::HttpClient;
+pub use client::{HttpClient, HttpClientBuilder};
 pub use headers::{create_l1_headers, create_l2_headers};
+pub use rate_limit::RateLimiter;
@@ -0,0 +1,184 @@
+use chrono::{DateTime, Utc};
+use futures_timer::Delay;
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::clock::{Clock, SystemClock};
+use crate::error::{Error, Result};
+
+/// Token bucket limiting the rate of HTTP requests
+///
+/// The bucket holds up to `capacity` tokens and refills continuously at
+/// `capacity` tokens per `period`. Every request takes one token, waiting for
+/// the next refill when the bucket is empty.
+///
+/// Wrap it in an [`Arc`](std::sync::Arc) and pass the same limiter to the
+/// [`HttpClient`](super::HttpClient) of every client sharing an API key, so the
+/// aggregate stays within the budget even when each client alone would not.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::{ClobClient, GammaClient, HttpClient, RateLimiter};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// # fn main() -> polymarket_rs::Result<()> {
+/// // 50 requests per 10 seconds, shared by both clients
+/// let limiter = Arc::new(RateLimiter::new(50, Duration::from_secs(10))?);
+///
+/// let clob_http = HttpClient::builder()
+///     .rate_limiter(limiter.clone())
+///     .build("https://clob.polymarket.com")?;
+/// let gamma_http = HttpClient::builder()
+///     .rate_limiter(limiter)
+///     .build("https://gamma-api.polymarket.com")?;
+///
+/// let clob = ClobClient::new("https://clob.polymarket.com").with_http_client(clob_http);
+/// let gamma = GammaClient::new("https://gamma-api.polymarket.com").with_http_client(gamma_http);
+/// # Ok(())
+/// # }
+/// ```
+pub struct RateLimiter {
+    capacity: f64,
+    /// Tokens added per second
+    refill_rate: f64,
+    clock: Box<dyn Clock>,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: DateTime<Utc>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `capacity` requests per `period`, starting full
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] if `capacity` or `period` is zero.
+    pub fn new(capacity: u32, period: Duration) -> Result<Self> {
+        if capacity == 0 || period.is_zero() {
+            return Err(Error::Config(
+                "Rate limit capacity and period must be non-zero".to_string(),
+            ));
+        }
+
+        let clock: Box<dyn Clock> = Box::new(SystemClock);
+        let capacity = f64::from(capacity);
+        Ok(Self {
+            capacity,
+            refill_rate: capacity / period.as_secs_f64(),
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                last_refill: clock.now(),
+            }),
+            clock,
+        })
+    }
+
+    /// Use a custom clock instead of the system time
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self.state
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .last_refill = self.clock.now();
+        self
+    }
+
+    /// Take a token if one is available, without waiting
+    pub fn try_acquire(&self) -> bool {
+        self.reserve().is_none()
+    }
+
+    /// Take a token, waiting until one is available
+    pub async fn acquire(&self) {
+        while let Some(wait) = self.reserve() {
+            Delay::new(wait).await;
+        }
+    }
+
+    /// Take a token, or return how long until the next one is available
+    fn reserve(&self) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let now = self.clock.now();
+        let elapsed = (now - state.last_refill).to_std().unwrap_or_default();
+        state.tokens = (state.tokens + elapsed.as_secs_f64() * self.refill_rate).min(self.capacity);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (1.0 - state.tokens) / self.refill_rate,
+            ))
+        }
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("capacity", &self.capacity)
+            .field("refill_rate", &self.refill_rate)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Clock advanced manually by the test
+    #[derive(Clone)]
+    struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+    impl ManualClock {
+        fn advance(&self, duration: Duration) {
+            let mut now = self.0.lock().unwrap();
+            *now += chrono::TimeDelta::from_std(duration).unwrap();
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[test]
+    fn test_bucket_drains_and_refills() {
+        let clock = ManualClock(Arc::new(Mutex::new(Utc::now())));
+        let limiter = RateLimiter::new(2, Duration::from_secs(1))
+            .unwrap()
+            .with_clock(clock.clone());
+
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+        assert_eq!(limiter.reserve(), Some(Duration::from_millis(500)));
+
+        clock.advance(Duration::from_millis(500));
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+
+        // Refills never exceed the capacity
+        clock.advance(Duration::from_secs(10));
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn test_rejects_zero_capacity() {
+        assert!(matches!(
+            RateLimiter::new(0, Duration::from_secs(1)),
+            Err(Error::Config(_))
+        ));
+    }
+}
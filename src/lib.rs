@@ -17,20 +17,31 @@
 //! - **EIP-712 Signing**: Full support for Ethereum wallet signatures
 //! - **Decimal Precision**: Accurate decimal math for prices and amounts
 //!
+//! ## Cargo Features
+//!
+//! - `websocket` (default): WebSocket streaming clients in the `websocket` module, built on tokio
+//! - `wasm`: Browser support. Build with `default-features = false, features = ["wasm"]`
+//!   for `wasm32-unknown-unknown`; the HTTP clients then use reqwest's fetch backend
+//!   and the websocket module is left out
+//!
 
 // Public modules
 pub mod client;
+pub mod clock;
 pub mod config;
 pub mod error;
+pub mod neg_risk;
+pub mod orderbook;
 pub mod orders;
 pub mod request;
 pub mod signing;
 pub mod types;
+pub mod utils;
+#[cfg(feature = "websocket")]
 pub mod websocket;
 
 // Internal modules
 mod http;
-mod utils;
 
 // Re-export commonly used types
 pub use alloy_primitives::Address;
@@ -38,19 +49,26 @@ pub use alloy_signer::k256;
 pub use alloy_signer_local::PrivateKeySigner;
 pub use error::{Error, Result};
 pub use types::{
-    ApiCreds, AssetType, ConditionId, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs,
+    Amount, ApiCreds, AssetType, ConditionId, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs,
     OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, TokenId,
 };
 
 // Re-export clients
 pub use client::{AuthenticatedClient, ClobClient, DataClient, GammaClient, TradingClient};
 
+// Re-export HTTP client configuration
+pub use http::{HttpClient, HttpClientBuilder, RateLimiter};
+
 // Re-export websocket clients
+#[cfg(feature = "websocket")]
 pub use websocket::{MarketWsClient, UserWsClient};
 
 // Re-export order builder
 pub use orders::OrderBuilder;
 
+// Re-export local order book
+pub use orderbook::LocalOrderBook;
+
 // Re-export signer trait
 pub use signing::EthSigner;
 
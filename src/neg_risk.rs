@@ -0,0 +1,133 @@
+//! Helpers for negative risk (multi-outcome) markets.
+//!
+//! In a neg-risk event exactly one outcome resolves Yes, so one No share on an
+//! outcome pays out exactly when one Yes share of any other outcome does. The
+//! neg-risk adapter lets holders convert No shares into Yes shares of every
+//! other outcome, which can be cheaper than trading into the same exposure.
+
+use rust_decimal::Decimal;
+
+use crate::types::OrderBookSummary;
+
+/// Number of Yes shares received per No share converted
+///
+/// Converting one No share of an outcome yields one Yes share of each of the
+/// other `outcome_count - 1` outcomes. Returns zero for events with fewer than
+/// two outcomes, where there is nothing to convert into.
+pub fn conversion_ratio(outcome_count: u32) -> Decimal {
+    Decimal::from(outcome_count.saturating_sub(1))
+}
+
+/// Top-of-book comparison of a No share against the Yes basket it converts into
+///
+/// Returned by [`quote_conversion`] and
+/// [`ClobClient::get_neg_risk_conversion_quote`](crate::client::ClobClient::get_neg_risk_conversion_quote).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionQuote {
+    /// Best ask for the No share being converted
+    pub no_price: Decimal,
+    /// Sum of the best bids for the Yes shares of every other outcome
+    pub yes_basket_price: Decimal,
+    /// Shares that can be bought and sold at these prices (smallest top level)
+    pub size: Decimal,
+}
+
+impl ConversionQuote {
+    /// Profit per share from buying No, converting and selling the Yes basket
+    ///
+    /// Negative when converting is worse than trading directly.
+    pub fn edge(&self) -> Decimal {
+        self.yes_basket_price - self.no_price
+    }
+
+    /// Whether converting beats trading at current top-of-book prices
+    pub fn is_favorable(&self) -> bool {
+        self.edge() > Decimal::ZERO
+    }
+}
+
+/// Quote a No to Yes-basket conversion from order books
+///
+/// # Arguments
+/// * `no_book` - Order book of the No token being converted
+/// * `yes_books` - Order books of the Yes tokens of every other outcome
+///
+/// # Returns
+/// `None` if `yes_books` is empty, the No book has no asks, or any Yes book has no bids
+pub fn quote_conversion(
+    no_book: &OrderBookSummary,
+    yes_books: &[OrderBookSummary],
+) -> Option<ConversionQuote> {
+    if yes_books.is_empty() {
+        return None;
+    }
+
+    let no_ask = no_book.best_ask()?;
+    let mut yes_basket_price = Decimal::ZERO;
+    let mut size = no_ask.size;
+
+    for book in yes_books {
+        let bid = book.best_bid()?;
+        yes_basket_price += bid.price;
+        size = size.min(bid.size);
+    }
+
+    Some(ConversionQuote {
+        no_price: no_ask.price,
+        yes_basket_price,
+        size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PriceLevel;
+    use rust_decimal_macros::dec;
+
+    fn book(bids: Vec<(Decimal, Decimal)>, asks: Vec<(Decimal, Decimal)>) -> OrderBookSummary {
+        let levels = |levels: Vec<(Decimal, Decimal)>| {
+            levels
+                .into_iter()
+                .map(|(price, size)| PriceLevel { price, size })
+                .collect()
+        };
+        OrderBookSummary {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            hash: "0xhash".to_string(),
+            timestamp: 0,
+            bids: levels(bids),
+            asks: levels(asks),
+        }
+    }
+
+    #[test]
+    fn test_conversion_ratio() {
+        assert_eq!(conversion_ratio(4), dec!(3));
+        assert_eq!(conversion_ratio(1), dec!(0));
+        assert_eq!(conversion_ratio(0), dec!(0));
+    }
+
+    #[test]
+    fn test_quote_conversion() {
+        let no_book = book(vec![], vec![(dec!(0.62), dec!(50)), (dec!(0.60), dec!(40))]);
+        let yes_books = vec![
+            book(
+                vec![(dec!(0.30), dec!(100)), (dec!(0.31), dec!(25))],
+                vec![],
+            ),
+            book(vec![(dec!(0.33), dec!(80))], vec![]),
+        ];
+
+        let quote = quote_conversion(&no_book, &yes_books).unwrap();
+        assert_eq!(quote.no_price, dec!(0.60));
+        assert_eq!(quote.yes_basket_price, dec!(0.64));
+        assert_eq!(quote.size, dec!(25));
+        assert_eq!(quote.edge(), dec!(0.04));
+        assert!(quote.is_favorable());
+
+        let empty_yes = vec![book(vec![], vec![])];
+        assert!(quote_conversion(&no_book, &empty_yes).is_none());
+    }
+}
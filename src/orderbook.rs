@@ -0,0 +1,486 @@
+//! Local order book maintained from WebSocket market events.
+//!
+//! [`LocalOrderBook`] tracks the bids and asks of a single asset by applying
+//! [`WsEvent`]s from the market stream: a [`WsEvent::Book`] snapshot resets the
+//! book and [`WsEvent::PriceChange`] deltas upsert or remove individual levels.
+
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+use crate::types::{
+    compute_book_hash, merge_levels, BookEvent, PriceChange, PriceLevel, Side, WsEvent,
+};
+
+/// In-memory order book for a single asset
+///
+/// Both sides are stored as `BTreeMap<price, size>` so the best levels can be
+/// read without sorting. Levels with a size of zero are never stored.
+///
+/// # Performance
+///
+/// Every [`apply`](Self::apply) inserts, updates or removes a level in
+/// `O(log n)`, and the best bid/ask are read from the ends of the maps in
+/// `O(log n)` without touching other levels. A sorted `Vec` would give slightly
+/// faster reads and better cache locality, but each insert or removal shifts
+/// the levels behind it (`O(n)`), and re-sorting after every delta costs
+/// `O(n log n)`. The tradeoff is memory: each map entry carries B-tree node
+/// overhead on top of the 32 bytes of price and size, roughly doubling the
+/// footprint of a packed `Vec`. Use [`with_max_depth`](Self::with_max_depth)
+/// to bound it when tracking many books.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::orderbook::LocalOrderBook;
+/// use polymarket_rs::types::WsEvent;
+///
+/// # fn example(events: Vec<WsEvent>) {
+/// let mut book: Option<LocalOrderBook> = None;
+/// for event in &events {
+///     match (&mut book, event) {
+///         (None, WsEvent::Book(snapshot)) => book = Some(LocalOrderBook::new(snapshot)),
+///         (Some(book), event) => {
+///             if book.apply(event) == Some(false) {
+///                 println!("{} may be out of sync", book.asset_id());
+///             }
+///         }
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    market: String,
+    asset_id: String,
+    timestamp: String,
+    hash: Option<String>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    max_depth: Option<usize>,
+}
+
+impl LocalOrderBook {
+    /// Create a new order book from a full snapshot
+    pub fn new(snapshot: &BookEvent) -> Self {
+        let mut book = Self {
+            market: snapshot.market.clone(),
+            asset_id: snapshot.asset_id.clone(),
+            timestamp: snapshot.timestamp.clone(),
+            hash: None,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            max_depth: None,
+        };
+        book.reset(snapshot);
+        book
+    }
+
+    /// Keep only the best `max_depth` levels on each side
+    ///
+    /// Deeper levels are discarded immediately and after every [`apply`](Self::apply),
+    /// which bounds memory when tracking many markets while keeping top-of-book
+    /// and near-touch depth accurate.
+    ///
+    /// **Note**: a truncated book no longer holds the full set of levels the
+    /// server hashed, so [`verify_hash`](Self::verify_hash) and the check done by
+    /// [`apply`](Self::apply) for price changes return `None`.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self.truncate();
+        self
+    }
+
+    /// Get the configured maximum depth per side, if any
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Get the market (condition) ID of this book
+    pub fn market(&self) -> &str {
+        &self.market
+    }
+
+    /// Get the asset (token) ID of this book
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// Get the timestamp of the last applied event
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    /// Get the server hash of the book as of the last applied event
+    ///
+    /// Taken from the last snapshot, or from the last price change that carried
+    /// a hash. `None` if a later price change carried no hash, as the earlier
+    /// hash no longer describes the book.
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Check the book against the server hash of the last applied event
+    ///
+    /// Recomputes the hash with [`compute_book_hash`] over the local levels
+    /// (bids by ascending price, asks by descending price, as the server sends
+    /// them) and compares it to [`hash`](Self::hash).
+    ///
+    /// The result is advisory. The book only keeps one size per price, so it
+    /// cannot reproduce the server's level strings exactly: a snapshot with
+    /// duplicate levels, or a price sent with a different number of decimals,
+    /// hashes differently even though the book is correct. Treat `Some(false)`
+    /// as a hint that the book may have drifted, and rate-limit any resync it
+    /// triggers rather than resubscribing on every mismatch.
+    ///
+    /// Returns `None` when there is no hash to check against, or when the book
+    /// is truncated by [`with_max_depth`](Self::with_max_depth).
+    pub fn verify_hash(&self) -> Option<bool> {
+        let hash = self.hash.as_deref()?;
+        if self.max_depth.is_some() {
+            return None;
+        }
+
+        let bids: Vec<PriceLevel> = self.bids.iter().map(to_level).collect();
+        let asks: Vec<PriceLevel> = self.asks.iter().rev().map(to_level).collect();
+        let expected =
+            compute_book_hash(&self.market, &self.asset_id, &self.timestamp, &bids, &asks);
+        let hash = hash.strip_prefix("0x").unwrap_or(hash);
+        Some(hash.eq_ignore_ascii_case(&expected))
+    }
+
+    /// Get the bid levels keyed by price (best bid is the last entry)
+    pub fn bids(&self) -> &BTreeMap<Decimal, Decimal> {
+        &self.bids
+    }
+
+    /// Get the ask levels keyed by price (best ask is the first entry)
+    pub fn asks(&self) -> &BTreeMap<Decimal, Decimal> {
+        &self.asks
+    }
+
+    /// Get the highest bid level
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.last_key_value().map(to_level)
+    }
+
+    /// Get the lowest ask level
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.first_key_value().map(to_level)
+    }
+
+    /// Get the midpoint between the best bid and best ask
+    ///
+    /// Returns `None` if either side is empty.
+    pub fn midpoint(&self) -> Option<Decimal> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / Decimal::TWO)
+    }
+
+    /// Get the best ask minus the best bid
+    ///
+    /// Returns `None` if either side is empty.
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Apply a market event to the book, checking it against the event's hash
+    ///
+    /// - [`WsEvent::Book`] replaces the whole book
+    /// - [`WsEvent::PriceChange`] upserts each changed level, removing it when the size is zero
+    ///
+    /// Events for other assets and other event types are ignored.
+    ///
+    /// Returns the result of the hash check, or `None` when nothing was checked:
+    /// the event was ignored, carried no hash, or the book is truncated by
+    /// [`with_max_depth`](Self::with_max_depth) (snapshots are still checked).
+    /// A snapshot is checked with [`BookEvent::verify_hash`] over its levels as
+    /// received; a price change with [`verify_hash`](Self::verify_hash), whose
+    /// result is only advisory.
+    pub fn apply(&mut self, event: &WsEvent) -> Option<bool> {
+        match event {
+            WsEvent::Book(snapshot) if snapshot.asset_id == self.asset_id => {
+                self.reset(snapshot);
+                Some(snapshot.verify_hash())
+            }
+            WsEvent::PriceChange(change) => {
+                let mut applied = false;
+                for price_change in &change.price_changes {
+                    if price_change.asset_id != self.asset_id {
+                        continue;
+                    }
+                    self.apply_price_change(price_change);
+                    applied = true;
+                }
+
+                if !applied {
+                    return None;
+                }
+                if let Some(ref timestamp) = change.timestamp {
+                    self.timestamp = timestamp.clone();
+                }
+                self.hash = change.hash.clone();
+                self.truncate();
+                self.verify_hash()
+            }
+            _ => None,
+        }
+    }
+
+    /// Replace the book contents with a snapshot
+    fn reset(&mut self, snapshot: &BookEvent) {
+        self.timestamp = snapshot.timestamp.clone();
+        self.hash = Some(snapshot.hash.clone());
+        self.bids = merge_levels(&snapshot.bids);
+        self.asks = merge_levels(&snapshot.asks);
+        self.truncate();
+    }
+
+    /// Upsert or remove a single price level
+    fn apply_price_change(&mut self, change: &PriceChange) {
+        let levels = match change.side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+
+        if change.size.is_zero() {
+            levels.remove(&change.price);
+        } else {
+            levels.insert(change.price, change.size);
+        }
+    }
+
+    /// Drop levels beyond the configured maximum depth
+    fn truncate(&mut self) {
+        let Some(max_depth) = self.max_depth else {
+            return;
+        };
+
+        // Worst bids are the lowest prices, worst asks are the highest
+        while self.bids.len() > max_depth {
+            self.bids.pop_first();
+        }
+        while self.asks.len() > max_depth {
+            self.asks.pop_last();
+        }
+    }
+}
+
+/// Turn a map entry back into a price level
+fn to_level((price, size): (&Decimal, &Decimal)) -> PriceLevel {
+    PriceLevel {
+        price: *price,
+        size: *size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PriceChangeEvent;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    fn snapshot() -> BookEvent {
+        BookEvent {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            timestamp: "1000".to_string(),
+            hash: "0xhash".to_string(),
+            bids: vec![
+                level(dec!(0.48), dec!(10)),
+                level(dec!(0.49), dec!(20)),
+                level(dec!(0.47), dec!(30)),
+            ],
+            asks: vec![
+                level(dec!(0.52), dec!(15)),
+                level(dec!(0.51), dec!(25)),
+                level(dec!(0.53), dec!(35)),
+            ],
+            last_trade_price: None,
+        }
+    }
+
+    fn price_change(side: Side, price: Decimal, size: Decimal) -> WsEvent {
+        WsEvent::PriceChange(PriceChangeEvent {
+            market: "0xmarket".to_string(),
+            timestamp: Some("2000".to_string()),
+            hash: None,
+            price_changes: vec![PriceChange {
+                asset_id: "123".to_string(),
+                side,
+                price,
+                size,
+            }],
+        })
+    }
+
+    #[test]
+    fn test_best_levels_from_snapshot() {
+        let book = LocalOrderBook::new(&snapshot());
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.49));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.51));
+        assert_eq!(book.bids().len(), 3);
+        assert_eq!(book.asks().len(), 3);
+    }
+
+    #[test]
+    fn test_snapshot_merges_duplicate_levels() {
+        let mut snapshot = snapshot();
+        snapshot.bids.push(level(dec!(0.49), dec!(5)));
+        let book = LocalOrderBook::new(&snapshot);
+
+        assert_eq!(book.bids().len(), 3);
+        assert_eq!(book.bids()[&dec!(0.49)], dec!(25));
+    }
+
+    #[test]
+    fn test_max_depth_truncates_snapshot() {
+        let book = LocalOrderBook::new(&snapshot()).with_max_depth(2);
+
+        let bids: Vec<Decimal> = book.bids().keys().copied().collect();
+        let asks: Vec<Decimal> = book.asks().keys().copied().collect();
+        assert_eq!(bids, vec![dec!(0.48), dec!(0.49)]);
+        assert_eq!(asks, vec![dec!(0.51), dec!(0.52)]);
+    }
+
+    #[test]
+    fn test_max_depth_applied_after_price_change() {
+        let mut book = LocalOrderBook::new(&snapshot()).with_max_depth(2);
+
+        // A better bid pushes the worst remaining bid out of the book
+        book.apply(&price_change(Side::Buy, dec!(0.50), dec!(5)));
+        let bids: Vec<Decimal> = book.bids().keys().copied().collect();
+        assert_eq!(bids, vec![dec!(0.49), dec!(0.50)]);
+
+        // A deeper ask is discarded straight away
+        book.apply(&price_change(Side::Sell, dec!(0.60), dec!(5)));
+        let asks: Vec<Decimal> = book.asks().keys().copied().collect();
+        assert_eq!(asks, vec![dec!(0.51), dec!(0.52)]);
+        assert_eq!(book.timestamp(), "2000");
+    }
+
+    #[test]
+    fn test_apply_upserts_removes_and_resets() {
+        let mut book = LocalOrderBook::new(&snapshot());
+
+        book.apply(&price_change(Side::Buy, dec!(0.48), dec!(12)));
+        assert_eq!(book.bids()[&dec!(0.48)], dec!(12));
+
+        // Zero size removes the level; removing a missing level is a no-op
+        book.apply(&price_change(Side::Sell, dec!(0.51), dec!(0)));
+        book.apply(&price_change(Side::Sell, dec!(0.55), dec!(0)));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(book.asks().len(), 2);
+
+        // Snapshots for other assets are ignored, our own replaces everything
+        let mut other = snapshot();
+        other.asset_id = "456".to_string();
+        other.bids.clear();
+        book.apply(&WsEvent::Book(other));
+        assert_eq!(book.bids().len(), 3);
+
+        let mut reset = snapshot();
+        reset.timestamp = "3000".to_string();
+        reset.asks.clear();
+        book.apply(&WsEvent::Book(reset));
+        assert_eq!(book.bids()[&dec!(0.48)], dec!(10));
+        assert!(book.asks().is_empty());
+        assert_eq!(book.timestamp(), "3000");
+    }
+
+    #[test]
+    fn test_replay_snapshot_and_deltas() {
+        let mut book = LocalOrderBook::new(&snapshot());
+        assert_eq!(book.midpoint(), Some(dec!(0.50)));
+        assert_eq!(book.spread(), Some(dec!(0.02)));
+        assert_eq!(book.hash(), Some("0xhash"));
+
+        book.apply(&price_change(Side::Buy, dec!(0.50), dec!(5)));
+        book.apply(&price_change(Side::Sell, dec!(0.51), dec!(0)));
+        book.apply(&price_change(Side::Buy, dec!(0.47), dec!(0)));
+
+        let bids: Vec<Decimal> = book.bids().keys().copied().collect();
+        let asks: Vec<Decimal> = book.asks().keys().copied().collect();
+        assert_eq!(bids, vec![dec!(0.48), dec!(0.49), dec!(0.50)]);
+        assert_eq!(asks, vec![dec!(0.52), dec!(0.53)]);
+        assert_eq!(book.midpoint(), Some(dec!(0.51)));
+        assert_eq!(book.spread(), Some(dec!(0.02)));
+        // The deltas carried no hash, so the snapshot's no longer applies
+        assert_eq!(book.hash(), None);
+    }
+
+    // Messages in the server's wire format. Each hash is the SHA-1 of the
+    // message's compact JSON with `hash` emptied, computed outside this crate:
+    // {"market":"0xmarket","asset_id":"123","timestamp":"1000","bids":[{"price":"0.48","size":"10"},{"price":"0.49","size":"20"}],"asks":[{"price":"0.52","size":"15"},{"price":"0.51","size":"25"}],"hash":""}
+    const SNAPSHOT: &str = r#"{"event_type":"book","market":"0xmarket","asset_id":"123","timestamp":"1000","bids":[{"price":"0.48","size":"10"},{"price":"0.49","size":"20"}],"asks":[{"price":"0.52","size":"15"},{"price":"0.51","size":"25"}],"hash":"7efeb4e090d710181c6eae11a693144a0a0aa871"}"#;
+    // The same book with a bid of 5 at 0.50 added, at timestamp 2000
+    const DELTA: &str = r#"{"event_type":"price_change","market":"0xmarket","timestamp":"2000","hash":"aab11c868f3383ef6a1a118818c5158bd3a79f71","price_changes":[{"asset_id":"123","side":"BUY","price":"0.50","size":"5"}]}"#;
+
+    #[test]
+    fn test_apply_checks_hash() {
+        let snapshot: WsEvent = serde_json::from_str(SNAPSHOT).unwrap();
+        let WsEvent::Book(ref book_event) = snapshot else {
+            unreachable!()
+        };
+        let mut book = LocalOrderBook::new(book_event);
+        assert_eq!(book.apply(&snapshot), Some(true));
+        assert_eq!(book.verify_hash(), Some(true));
+        assert_eq!(book.clone().with_max_depth(1).verify_hash(), None);
+
+        let WsEvent::PriceChange(mut change) = serde_json::from_str(DELTA).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(
+            book.apply(&WsEvent::PriceChange(change.clone())),
+            Some(true)
+        );
+
+        // A hash that does not match the book is flagged
+        change.hash = Some("0xdeadbeef".to_string());
+        assert_eq!(book.apply(&WsEvent::PriceChange(change)), Some(false));
+        assert_eq!(book.verify_hash(), Some(false));
+
+        // Nothing to check once a delta carries no hash, or for other assets
+        assert_eq!(
+            book.apply(&price_change(Side::Sell, dec!(0.51), dec!(0))),
+            None
+        );
+        assert_eq!(book.verify_hash(), None);
+        let mut other = price_change(Side::Buy, dec!(0.40), dec!(1));
+        if let WsEvent::PriceChange(ref mut change) = other {
+            change.price_changes[0].asset_id = "456".to_string();
+        }
+        assert_eq!(book.apply(&other), None);
+    }
+
+    #[test]
+    fn test_snapshot_hash_checked_as_received() {
+        // A snapshot repeating the 0.49 bid, hashed over the levels as sent:
+        // {"market":"0xmarket","asset_id":"123","timestamp":"1000","bids":[{"price":"0.48","size":"10"},{"price":"0.49","size":"15"},{"price":"0.49","size":"5"}],"asks":[{"price":"0.52","size":"15"},{"price":"0.51","size":"25"}],"hash":""}
+        let json = SNAPSHOT
+            .replace(
+                r#"{"price":"0.49","size":"20"}"#,
+                r#"{"price":"0.49","size":"15"},{"price":"0.49","size":"5"}"#,
+            )
+            .replace(
+                "7efeb4e090d710181c6eae11a693144a0a0aa871",
+                "ef780a9ccab6f2ab3c8d635d286218252170c963",
+            );
+        let snapshot: WsEvent = serde_json::from_str(&json).unwrap();
+        let WsEvent::Book(ref book_event) = snapshot else {
+            unreachable!()
+        };
+
+        // The snapshot verifies as received, even on a truncated book
+        let mut book = LocalOrderBook::new(book_event);
+        assert_eq!(book.apply(&snapshot), Some(true));
+        assert_eq!(book.clone().with_max_depth(1).apply(&snapshot), Some(true));
+
+        // The merged local levels cannot reproduce it
+        assert_eq!(book.bids()[&dec!(0.49)], dec!(20));
+        assert_eq!(book.verify_hash(), Some(false));
+    }
+}
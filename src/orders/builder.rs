@@ -1,33 +1,175 @@
-use super::rounding::{decimal_to_token_u64, fix_amount_rounding, ROUNDING_CONFIG};
+use super::rounding::{decimal_to_token_units, fix_amount_rounding, ROUNDING_CONFIG};
+use crate::clock::{Clock, SystemClock};
 use crate::config::get_contract_config;
 use crate::error::{Error, Result};
-use crate::orders::RoundConfig;
+use crate::orders::{calculate_market_price, calculate_shares_for_usdc, RoundConfig};
 use crate::signing::{sign_order_message, EthSigner, Order};
 use crate::types::{
-    CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, Side, SignatureType,
-    SignedOrderRequest,
+    Amount, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, MarketOrderSize, OrderArgs,
+    OrderType, PriceLevel, Side, SignatureType, SignedOrderRequest,
 };
-use crate::utils::get_current_unix_time_secs;
 use alloy_primitives::{Address, U256};
+use alloy_signer_local::PrivateKeySigner;
 use rand::{thread_rng, Rng};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::ToZero;
 use std::str::FromStr;
 
 /// Generate a random seed for order salt
-fn generate_seed() -> Result<u64> {
+fn generate_seed(clock: &dyn Clock) -> Result<u64> {
     let mut rng = thread_rng();
     let y: f64 = rng.gen();
-    let timestamp = get_current_unix_time_secs()?;
+    let timestamp = clock.unix_time_secs()?;
     let a: f64 = timestamp as f64 * y;
     Ok(a as u64)
 }
 
+/// Error for an order amount that overflowed
+fn overflow(what: &str) -> Error {
+    Error::ArithmeticOverflow(format!("{} is out of range", what))
+}
+
+/// Reject a price that is not positive once rounded to the tick size
+fn check_price(price: Decimal) -> Result<()> {
+    if price <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Invalid price: {} must be positive at this tick size",
+            price
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a negative order size or amount
+fn check_not_negative(field: &str, value: Decimal) -> Result<()> {
+    if value < Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Invalid {}: {} must not be negative",
+            field, value
+        )));
+    }
+    Ok(())
+}
+
+/// Reject orders whose fee rate exceeds the configured maximum
+fn check_fee_rate(extras: &ExtraOrderArgs, options: &CreateOrderOptions) -> Result<()> {
+    match options.max_fee_rate_bps {
+        Some(max) if extras.fee_rate_bps > max => Err(Error::InvalidOrder(format!(
+            "Fee rate {} bps exceeds maximum of {} bps",
+            extras.fee_rate_bps, max
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a market order the given levels cannot fully fill at `price` or better
+///
+/// Liquidity is counted in the unit of `amount`: shares, or notional for a collateral amount.
+fn check_fok_liquidity(
+    side: Side,
+    amount: Amount,
+    price: Decimal,
+    levels: &[PriceLevel],
+) -> Result<()> {
+    let fillable = levels.iter().filter(|level| match side {
+        Side::Buy => level.price <= price,
+        Side::Sell => level.price >= price,
+    });
+    let available: Decimal = match amount {
+        Amount::Shares(_) => fillable.map(|level| level.size).sum(),
+        Amount::Collateral(_) => fillable.map(|level| level.price * level.size).sum(),
+    };
+
+    let amount = amount.value();
+    if available < amount {
+        return Err(Error::InvalidOrder(format!(
+            "FOK order for {} cannot be filled at {}: only {} available, short by {}",
+            amount,
+            price,
+            available,
+            amount - available
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a market order whose price is worse than the allowed slippage
+///
+/// The limit is `reference * (1 + bps)` for a buy and `reference * (1 - bps)` for a sell.
+fn check_slippage(side: Side, price: Decimal, reference: Decimal, max_bps: u32) -> Result<()> {
+    let tolerance = reference * Decimal::from(max_bps) / Decimal::from(10_000);
+    let (allowed, exceeded) = match side {
+        Side::Buy => (reference + tolerance, price > reference + tolerance),
+        Side::Sell => (reference - tolerance, price < reference - tolerance),
+    };
+
+    if exceeded {
+        return Err(Error::InvalidOrder(format!(
+            "Market price {} exceeds slippage limit: allowed {} ({} bps from {})",
+            price, allowed, max_bps, reference
+        )));
+    }
+    Ok(())
+}
+
+/// Convert a market order amount to shares, see [`OrderBuilder::market_order_size`]
+fn resolve_market_order_size(
+    order_args: &MarketOrderArgs,
+    price: Decimal,
+    levels: Option<&[PriceLevel]>,
+    round_config: &RoundConfig,
+) -> Result<MarketOrderSize> {
+    let round_shares = |shares: Decimal| shares.round_dp_with_strategy(round_config.size, ToZero);
+
+    match (order_args.side, order_args.amount) {
+        (_, Amount::Shares(shares)) => Ok(MarketOrderSize {
+            shares: round_shares(shares),
+            unspent: Decimal::ZERO,
+        }),
+        (Side::Buy, Amount::Collateral(collateral)) => {
+            check_not_negative("amount", collateral)?;
+            let spent = round_shares(collateral);
+            let shares = match levels {
+                Some(levels) => calculate_shares_for_usdc(levels, spent, Side::Buy)?.0,
+                None => {
+                    let price = price.round_dp_with_strategy(round_config.price, ToZero);
+                    check_price(price)?;
+                    spent.checked_div(price).ok_or_else(|| overflow("shares"))?
+                }
+            };
+            Ok(MarketOrderSize {
+                shares: round_shares(shares),
+                unspent: collateral - spent,
+            })
+        }
+        (Side::Sell, Amount::Collateral(collateral)) => {
+            check_not_negative("amount", collateral)?;
+            let (shares, avg_price) = match levels {
+                Some(levels) => calculate_shares_for_usdc(levels, collateral, Side::Sell)?,
+                None => {
+                    check_price(price)?;
+                    let shares = collateral
+                        .checked_div(price)
+                        .ok_or_else(|| overflow("shares"))?;
+                    (shares, price)
+                }
+            };
+            let shares = round_shares(shares);
+            Ok(MarketOrderSize {
+                shares,
+                unspent: (collateral - shares * avg_price).max(Decimal::ZERO),
+            })
+        }
+    }
+}
+
 /// Builder for creating and signing orders
 pub struct OrderBuilder {
     signer: Box<dyn EthSigner>,
     sig_type: SignatureType,
     funder: Address,
+    signer_address: Address,
+    clock: Box<dyn Clock>,
 }
 
 impl OrderBuilder {
@@ -50,9 +192,101 @@ impl OrderBuilder {
             signer: Box::new(signer),
             sig_type,
             funder,
+            signer_address: signer_addr,
+            clock: Box::new(SystemClock),
         }
     }
 
+    /// Create a new OrderBuilder from a hex-encoded private key
+    ///
+    /// The key may be given with or without the `0x` prefix. It is loaded into a
+    /// [`PrivateKeySigner`], the same signer type used throughout the examples.
+    ///
+    /// # Arguments
+    /// * `private_key` - The hex-encoded private key
+    /// * `sig_type` - The signature type (defaults to EOA if None)
+    /// * `funder` - The address funding the order (defaults to signer address if None)
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] if the key is not a valid secp256k1 private key.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::orders::OrderBuilder;
+    ///
+    /// let private_key = std::env::var("PRIVATE_KEY").expect("PRIVATE_KEY must be set");
+    /// let builder = OrderBuilder::from_private_key(&private_key, None, None).unwrap();
+    /// ```
+    pub fn from_private_key(
+        private_key: &str,
+        sig_type: Option<SignatureType>,
+        funder: Option<Address>,
+    ) -> Result<Self> {
+        let private_key = private_key.trim();
+        let hex = private_key.strip_prefix("0x").unwrap_or(private_key);
+        // Don't echo the key back in the error message
+        let signer = PrivateKeySigner::from_str(hex)
+            .map_err(|_| Error::Config("Invalid private key".to_string()))?;
+
+        Ok(Self::new(signer, sig_type, funder))
+    }
+
+    /// Override the address placed in the order's `signer` field
+    ///
+    /// Orders are still signed with the builder's key; only the declared signer
+    /// changes. This is meant for advanced proxy setups where the exchange checks
+    /// the signature against a different account than the signing key's address.
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] if the address is zero, or if the signature type is
+    /// EOA and the address differs from the signing key's (EOA signatures are
+    /// recovered directly and would never verify).
+    pub fn with_signer_address(mut self, signer_address: Address) -> Result<Self> {
+        if signer_address == Address::ZERO {
+            return Err(Error::Config(
+                "Signer address override cannot be the zero address".to_string(),
+            ));
+        }
+        if self.sig_type == SignatureType::Eoa && signer_address != self.signer.address() {
+            return Err(Error::Config(format!(
+                "EOA orders must be signed by the declared signer: {} does not match key address {}",
+                signer_address,
+                self.signer.address()
+            )));
+        }
+
+        self.signer_address = signer_address;
+        Ok(self)
+    }
+
+    /// Use a custom clock instead of the system time
+    ///
+    /// Mainly useful in tests to make time-dependent behavior deterministic.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Get the clock used by this builder
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// Get the address placed in the `signer` field of orders
+    ///
+    /// This is the signing key's address unless overridden with
+    /// [`with_signer_address`](Self::with_signer_address).
+    pub fn signer_address(&self) -> Address {
+        self.signer_address
+    }
+
+    /// Get the address placed in the `maker` field of orders
+    ///
+    /// This is the funder given at construction, or the signing key's address.
+    pub fn funder_address(&self) -> Address {
+        self.funder
+    }
+
     /// Get the signature type as u8
     pub fn get_sig_type(&self) -> u8 {
         self.sig_type.to_u8()
@@ -65,29 +299,35 @@ impl OrderBuilder {
         size: Decimal,
         price: Decimal,
         round_config: &RoundConfig,
-    ) -> (u64, u64) {
+    ) -> Result<(u64, u64)> {
         // Use ToZero for prices to ensure they never round to 1.0 (invalid for prediction markets)
         let raw_price = price.round_dp_with_strategy(round_config.price, ToZero);
+        check_price(raw_price)?;
+        check_not_negative("size", size)?;
 
         match side {
             Side::Buy => {
                 let raw_taker_amt = size.round_dp_with_strategy(round_config.size, ToZero);
-                let raw_maker_amt = raw_taker_amt * raw_price;
+                let raw_maker_amt = raw_taker_amt
+                    .checked_mul(raw_price)
+                    .ok_or_else(|| overflow("maker amount"))?;
                 let raw_maker_amt = fix_amount_rounding(raw_maker_amt, round_config);
-                (
-                    decimal_to_token_u64(raw_maker_amt),
-                    decimal_to_token_u64(raw_taker_amt),
-                )
+                Ok((
+                    decimal_to_token_units(raw_maker_amt)?,
+                    decimal_to_token_units(raw_taker_amt)?,
+                ))
             }
             Side::Sell => {
                 let raw_maker_amt = size.round_dp_with_strategy(round_config.size, ToZero);
-                let raw_taker_amt = raw_maker_amt * raw_price;
+                let raw_taker_amt = raw_maker_amt
+                    .checked_mul(raw_price)
+                    .ok_or_else(|| overflow("taker amount"))?;
                 let raw_taker_amt = fix_amount_rounding(raw_taker_amt, round_config);
 
-                (
-                    decimal_to_token_u64(raw_maker_amt),
-                    decimal_to_token_u64(raw_taker_amt),
-                )
+                Ok((
+                    decimal_to_token_units(raw_maker_amt)?,
+                    decimal_to_token_units(raw_taker_amt)?,
+                ))
             }
         }
     }
@@ -99,27 +339,76 @@ impl OrderBuilder {
         amount: Decimal,
         price: Decimal,
         round_config: &RoundConfig,
-    ) -> (u64, u64) {
+    ) -> Result<(u64, u64)> {
+        check_not_negative("amount", amount)?;
         let raw_maker_amt = amount.round_dp_with_strategy(round_config.size, ToZero);
         // Use ToZero for prices to ensure they never round to 1.0 (invalid for prediction markets)
         let raw_price = price.round_dp_with_strategy(round_config.price, ToZero);
+        check_price(raw_price)?;
 
         let raw_taker_amt = match side {
-            Side::Buy => raw_maker_amt / raw_price,
-            Side::Sell => raw_maker_amt * raw_price,
-        };
+            Side::Buy => raw_maker_amt.checked_div(raw_price),
+            Side::Sell => raw_maker_amt.checked_mul(raw_price),
+        }
+        .ok_or_else(|| overflow("taker amount"))?;
 
         let raw_taker_amt = fix_amount_rounding(raw_taker_amt, round_config);
 
-        (
-            decimal_to_token_u64(raw_maker_amt),
-            decimal_to_token_u64(raw_taker_amt),
-        )
+        Ok((
+            decimal_to_token_units(raw_maker_amt)?,
+            decimal_to_token_units(raw_taker_amt)?,
+        ))
+    }
+
+    /// Size of a market order in shares, and the collateral it leaves unspent
+    ///
+    /// Shares are rounded down to the market's size precision. An
+    /// [`Amount::Collateral`] order walks the `options.fok_book` levels (asks for
+    /// a buy, bids for a sell), or divides by `price` without them, to find the
+    /// shares it trades:
+    ///
+    /// - A buy spends the collateral rounded down to the size precision; the dust
+    ///   below it is unspent, and `shares` is what the book fills for the rest
+    /// - A sell reports the collateral the rounded-down shares do not cover as
+    ///   unspent
+    ///
+    /// # Errors
+    /// Returns an error if `options.tick_size` is missing or invalid, or if the
+    /// book cannot cover a collateral order. A negative amount, or a price that
+    /// rounds to zero at the tick size, is an [`Error::InvalidParameter`].
+    pub fn market_order_size(
+        &self,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        options: &CreateOrderOptions,
+    ) -> Result<MarketOrderSize> {
+        let tick_size = options
+            .tick_size
+            .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
+        let round_config = ROUNDING_CONFIG
+            .get(&tick_size)
+            .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
+
+        resolve_market_order_size(order_args, price, options.fok_book.as_deref(), round_config)
     }
 
     /// Create a market order
     ///
     /// Market orders are executed at the best available price by walking the order book.
+    /// When [`CreateOrderOptions::fok_book`] is set, the order is rejected with
+    /// [`Error::InvalidOrder`] (including the shortfall) if those levels cannot fill
+    /// `amount` at `price` or better, instead of being signed and killed by the exchange.
+    ///
+    /// With [`CreateOrderOptions::max_slippage`], the weighted average price is
+    /// recomputed from the `fok_book` levels (or taken from `price` without them)
+    /// and the order is rejected with [`Error::InvalidOrder`] if it is worse than
+    /// the reference price by more than the tolerance.
+    ///
+    /// A buy for [`Amount::Collateral`] spends that amount rounded down to the size
+    /// precision, receiving at least the shares it buys at `price`; other amounts
+    /// are converted to shares first. Use
+    /// [`create_market_order_with_size`](Self::create_market_order_with_size) to
+    /// also get the shares traded and the collateral left unspent.
     pub fn create_market_order(
         &self,
         chain_id: u64,
@@ -128,6 +417,27 @@ impl OrderBuilder {
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        self.create_market_order_with_size(chain_id, order_args, price, extras, options)
+            .map(|(order, _)| order)
+    }
+
+    /// Create a market order, along with its size
+    ///
+    /// Same as [`create_market_order`](Self::create_market_order), but also returns
+    /// the [`MarketOrderSize`] computed by [`market_order_size`](Self::market_order_size):
+    /// the shares the order trades (walking `options.fok_book` when set) and the
+    /// collateral it leaves unspent.
+    pub fn create_market_order_with_size(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<(SignedOrderRequest, MarketOrderSize)> {
+        order_args.validate()?;
+        extras.validate()?;
+
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -140,15 +450,51 @@ impl OrderBuilder {
             .get(&tick_size)
             .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
 
-        let (maker_amount, taker_amount) =
-            self.get_market_order_amounts(order_args.side, order_args.amount, price, round_config);
+        check_fee_rate(extras, &options)?;
+
+        if let Some(levels) = &options.fok_book {
+            // Check against the price as it will be signed
+            let price = price.round_dp_with_strategy(round_config.price, ToZero);
+            check_fok_liquidity(order_args.side, order_args.amount, price, levels)?;
+        }
+
+        if let Some(max_bps) = options.max_slippage_bps {
+            let reference = options
+                .reference_price
+                .ok_or_else(|| Error::MissingField("reference_price".to_string()))?;
+            // Re-price against the book when one is given, in case it moved
+            let realized = match (&options.fok_book, order_args.amount) {
+                (Some(levels), Amount::Shares(shares)) => {
+                    calculate_market_price(levels, shares, order_args.side)?
+                }
+                (Some(levels), Amount::Collateral(collateral)) => {
+                    calculate_shares_for_usdc(levels, collateral, order_args.side)?.1
+                }
+                (None, _) => price,
+            };
+            check_slippage(order_args.side, realized, reference, max_bps)?;
+        }
+
+        let size = resolve_market_order_size(
+            order_args,
+            price,
+            options.fok_book.as_deref(),
+            round_config,
+        )?;
+        let (maker_amount, taker_amount) = match (order_args.side, order_args.amount) {
+            // Sign the collateral itself; `price` bounds the shares received
+            (Side::Buy, Amount::Collateral(collateral)) => {
+                self.get_market_order_amounts(Side::Buy, collateral, price, round_config)?
+            }
+            (side, _) => self.get_order_amounts(side, size.shares, price, round_config)?,
+        };
 
         let contract_config = get_contract_config(chain_id, neg_risk)?;
 
         let exchange_address = Address::from_str(&contract_config.exchange)
             .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
 
-        self.build_signed_order(
+        let order = self.build_signed_order(
             order_args.token_id.clone(),
             order_args.side,
             chain_id,
@@ -157,12 +503,19 @@ impl OrderBuilder {
             taker_amount,
             0, // Market orders have 0 expiration
             extras,
-        )
+            generate_seed(self.clock())?,
+        )?;
+        Ok((order, size))
     }
 
     /// Create a limit order
     ///
-    /// Limit orders are executed at a specific price or better.
+    /// Limit orders are executed at a specific price or better. A non-zero
+    /// `expiration` makes this a GTD order, which must expire at least
+    /// `options.gtd_buffer_secs` (60 by default) from now.
+    ///
+    /// The price and size are checked with [`OrderArgs::validate`] against the
+    /// tick size before the order is signed.
     pub fn create_order(
         &self,
         chain_id: u64,
@@ -171,6 +524,80 @@ impl OrderBuilder {
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        let (tick_size, round_config, exchange_address) =
+            self.prepare_limit_orders(chain_id, expiration, extras, &options)?;
+
+        self.sign_limit_order(
+            chain_id,
+            order_args,
+            expiration,
+            extras,
+            tick_size,
+            round_config,
+            exchange_address,
+            generate_seed(self.clock())?,
+        )
+    }
+
+    /// Create several limit orders sharing the same options
+    ///
+    /// Equivalent to calling [`create_order`](Self::create_order) for each entry
+    /// of `orders`, but the tick size, neg-risk flag, fee rate and expiration are
+    /// validated once, and every order gets a distinct salt. The batch is atomic:
+    /// if any order fails, no orders are returned.
+    ///
+    /// # Errors
+    /// Errors in the shared options are returned as is. An error building one of
+    /// the orders is returned as [`Error::BatchOrderFailed`] with its index.
+    pub fn create_order_batch(
+        &self,
+        chain_id: u64,
+        orders: &[OrderArgs],
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<Vec<SignedOrderRequest>> {
+        let (tick_size, round_config, exchange_address) =
+            self.prepare_limit_orders(chain_id, expiration, extras, &options)?;
+
+        // Consecutive salts are distinct by construction, whatever the clock
+        let base_seed = generate_seed(self.clock())?;
+        orders
+            .iter()
+            .enumerate()
+            .map(|(index, order_args)| {
+                let seed = base_seed.wrapping_add(index as u64);
+
+                self.sign_limit_order(
+                    chain_id,
+                    order_args,
+                    expiration,
+                    extras,
+                    tick_size,
+                    round_config,
+                    exchange_address,
+                    seed,
+                )
+                .map_err(|error| Error::BatchOrderFailed {
+                    index,
+                    error: Box::new(error),
+                })
+            })
+            .collect()
+    }
+
+    /// Validate the options shared by limit orders
+    ///
+    /// Returns the tick size, its rounding config and the exchange address.
+    fn prepare_limit_orders(
+        &self,
+        chain_id: u64,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: &CreateOrderOptions,
+    ) -> Result<(Decimal, &'static RoundConfig, Address)> {
+        extras.validate()?;
+
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -183,18 +610,49 @@ impl OrderBuilder {
             .get(&tick_size)
             .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
 
-        let (maker_amount, taker_amount) = self.get_order_amounts(
-            order_args.side,
-            order_args.size,
-            order_args.price,
-            round_config,
-        );
+        check_fee_rate(extras, options)?;
+
+        if expiration != 0 {
+            let buffer_secs = options
+                .gtd_buffer_secs
+                .unwrap_or(CreateOrderOptions::DEFAULT_GTD_BUFFER_SECS);
+            OrderType::Gtd.validate_expiration_with_buffer(
+                expiration,
+                self.clock.unix_time_secs()?,
+                buffer_secs,
+            )?;
+        }
 
         let contract_config = get_contract_config(chain_id, neg_risk)?;
 
         let exchange_address = Address::from_str(&contract_config.exchange)
             .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
 
+        Ok((tick_size, round_config, exchange_address))
+    }
+
+    /// Compute the amounts of a limit order and sign it
+    #[allow(clippy::too_many_arguments)]
+    fn sign_limit_order(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        tick_size: Decimal,
+        round_config: &RoundConfig,
+        exchange_address: Address,
+        seed: u64,
+    ) -> Result<SignedOrderRequest> {
+        order_args.validate(tick_size)?;
+
+        let (maker_amount, taker_amount) = self.get_order_amounts(
+            order_args.side,
+            order_args.size,
+            order_args.price,
+            round_config,
+        )?;
+
         self.build_signed_order(
             order_args.token_id.clone(),
             order_args.side,
@@ -204,6 +662,7 @@ impl OrderBuilder {
             taker_amount,
             expiration,
             extras,
+            seed,
         )
     }
 
@@ -219,8 +678,8 @@ impl OrderBuilder {
         taker_amount: u64,
         expiration: u64,
         extras: &ExtraOrderArgs,
+        seed: u64,
     ) -> Result<SignedOrderRequest> {
-        let seed = generate_seed()?;
         let taker_address = Address::from_str(&extras.taker)
             .map_err(|e| Error::InvalidParameter(format!("Invalid taker address: {}", e)))?;
 
@@ -230,7 +689,7 @@ impl OrderBuilder {
         let order = Order {
             salt: U256::from(seed),
             maker: self.funder,
-            signer: self.signer.address(),
+            signer: self.signer_address,
             taker: taker_address,
             tokenId: u256_token_id,
             makerAmount: U256::from(maker_amount),
@@ -247,7 +706,7 @@ impl OrderBuilder {
         Ok(SignedOrderRequest {
             salt: seed,
             maker: self.funder.to_checksum(None),
-            signer: self.signer.address().to_checksum(None),
+            signer: self.signer_address.to_checksum(None),
             taker: taker_address.to_checksum(None),
             token_id,
             maker_amount: maker_amount.to_string(),
@@ -265,16 +724,86 @@ impl OrderBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_signer_local::PrivateKeySigner;
+    use crate::clock::FixedClock;
+    use rust_decimal_macros::dec;
+    use std::collections::HashSet;
 
     #[test]
     fn test_generate_seed() {
-        let seed1 = generate_seed().unwrap();
-        let seed2 = generate_seed().unwrap();
+        let seed1 = generate_seed(&SystemClock).unwrap();
+        let seed2 = generate_seed(&SystemClock).unwrap();
         // Seeds should be different (very unlikely to be the same)
         assert_ne!(seed1, seed2);
     }
 
+    #[test]
+    fn test_extreme_amounts_return_overflow() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let round_config = ROUNDING_CONFIG.get(&dec!(0.01)).unwrap();
+
+        let result = builder.get_order_amounts(Side::Buy, Decimal::MAX, dec!(0.5), round_config);
+        assert!(matches!(result, Err(Error::ArithmeticOverflow(_))));
+
+        let result =
+            builder.get_market_order_amounts(Side::Buy, Decimal::MAX, dec!(0.5), round_config);
+        assert!(matches!(result, Err(Error::ArithmeticOverflow(_))));
+    }
+
+    #[test]
+    fn test_invalid_amount_inputs_name_the_field() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let round_config = ROUNDING_CONFIG.get(&dec!(0.01)).unwrap();
+
+        // 0.001 rounds to a zero price at a 0.01 tick
+        let result =
+            builder.get_market_order_amounts(Side::Buy, dec!(100), dec!(0.001), round_config);
+        assert!(matches!(result, Err(Error::InvalidParameter(msg)) if msg.contains("price")));
+
+        let result = builder.get_order_amounts(Side::Sell, dec!(10), dec!(0), round_config);
+        assert!(matches!(result, Err(Error::InvalidParameter(msg)) if msg.contains("price")));
+
+        let result = builder.get_order_amounts(Side::Buy, dec!(-10), dec!(0.5), round_config);
+        assert!(matches!(result, Err(Error::InvalidParameter(msg)) if msg.contains("size")));
+
+        let result =
+            builder.get_market_order_amounts(Side::Sell, dec!(-10), dec!(0.5), round_config);
+        assert!(matches!(result, Err(Error::InvalidParameter(msg)) if msg.contains("amount")));
+    }
+
+    #[test]
+    fn test_large_order_amounts_exact() {
+        // 9,900 USDC for 10,000 shares is well past u32::MAX in token units
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let order_args = OrderArgs::new("1234", dec!(0.99), dec!(10000), Side::Buy);
+        let signed = builder
+            .create_order(
+                137,
+                &order_args,
+                0,
+                &ExtraOrderArgs::default(),
+                CreateOrderOptions::new()
+                    .tick_size(dec!(0.01))
+                    .neg_risk(false),
+            )
+            .unwrap();
+
+        assert_eq!(signed.maker_amount, "9900000000");
+        assert_eq!(signed.taker_amount, "10000000000");
+    }
+
+    #[test]
+    fn test_from_private_key() {
+        let key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcc4a38a28f5d3c3e0";
+        let bare = OrderBuilder::from_private_key(key, None, None).unwrap();
+        let prefixed = OrderBuilder::from_private_key(&format!("0x{}", key), None, None).unwrap();
+
+        assert_eq!(bare.signer.address(), prefixed.signer.address());
+        assert_eq!(bare.funder, bare.signer.address());
+        assert_eq!(bare.signer_address(), bare.signer.address());
+        assert_eq!(bare.funder_address(), bare.signer.address());
+        assert!(OrderBuilder::from_private_key("0xnot-a-key", None, None).is_err());
+    }
+
     #[test]
     fn test_price_0_999_does_not_round_to_1() {
         // Create a test signer
@@ -287,8 +816,9 @@ mod tests {
         let price = Decimal::from_str("0.999").unwrap();
         let size = Decimal::from_str("30.0").unwrap();
 
-        let (maker_amount, taker_amount) =
-            builder.get_order_amounts(Side::Sell, size, price, round_config);
+        let (maker_amount, taker_amount) = builder
+            .get_order_amounts(Side::Sell, size, price, round_config)
+            .unwrap();
 
         // Verify amounts are NOT equal (which would mean price = 1.0)
         assert_ne!(
@@ -302,4 +832,414 @@ mod tests {
         assert_eq!(maker_amount, 30_000_000);
         assert_eq!(taker_amount, 27_000_000);
     }
+
+    /// Rebuild the EIP-712 order struct from a signed order request
+    fn order_from_request(signed: &SignedOrderRequest, side: Side) -> Order {
+        Order {
+            salt: U256::from(signed.salt),
+            maker: Address::from_str(&signed.maker).unwrap(),
+            signer: Address::from_str(&signed.signer).unwrap(),
+            taker: Address::from_str(&signed.taker).unwrap(),
+            tokenId: U256::from_str_radix(&signed.token_id, 10).unwrap(),
+            makerAmount: U256::from_str_radix(&signed.maker_amount, 10).unwrap(),
+            takerAmount: U256::from_str_radix(&signed.taker_amount, 10).unwrap(),
+            expiration: U256::from_str_radix(&signed.expiration, 10).unwrap(),
+            nonce: U256::from_str_radix(&signed.nonce, 10).unwrap(),
+            feeRateBps: U256::from_str_radix(&signed.fee_rate_bps, 10).unwrap(),
+            side: side.to_u8(),
+            signatureType: signed.signature_type,
+        }
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let order_args = OrderArgs::new("1234", dec!(0.55), dec!(10), Side::Buy);
+        let signed = builder
+            .create_order(
+                137,
+                &order_args,
+                0,
+                &ExtraOrderArgs::default(),
+                CreateOrderOptions::new()
+                    .tick_size(dec!(0.01))
+                    .neg_risk(false),
+            )
+            .unwrap();
+        let exchange =
+            Address::from_str(&get_contract_config(137, false).unwrap().exchange).unwrap();
+
+        assert!(signed.verify_signature(137, exchange).unwrap());
+        assert!(!signed.verify_signature(80002, exchange).unwrap());
+        assert!(!signed.verify_signature(137, Address::ZERO).unwrap());
+
+        let mut tampered = signed.clone();
+        tampered.maker_amount = "1".to_string();
+        assert!(!tampered.verify_signature(137, exchange).unwrap());
+
+        let mut malformed = signed;
+        malformed.signature = "0x1234".to_string();
+        assert!(malformed.verify_signature(137, exchange).is_err());
+    }
+
+    #[test]
+    fn test_amoy_order_signed_for_testnet_exchange() {
+        use crate::config::chains::{POLYGON_AMOY_TESTNET, POLYGON_MAINNET};
+
+        let signer = PrivateKeySigner::random();
+        let builder = OrderBuilder::new(signer.clone(), None, None);
+        let order_args = OrderArgs::new("1234", dec!(0.55), dec!(10), Side::Buy);
+
+        for neg_risk in [false, true] {
+            let signed = builder
+                .create_order(
+                    POLYGON_AMOY_TESTNET,
+                    &order_args,
+                    0,
+                    &ExtraOrderArgs::default(),
+                    CreateOrderOptions::new()
+                        .tick_size(dec!(0.01))
+                        .neg_risk(neg_risk),
+                )
+                .unwrap();
+
+            // Signatures are deterministic, so re-signing against the expected
+            // domain must reproduce the order signature exactly
+            let order = order_from_request(&signed, Side::Buy);
+            let sign_with = |chain_id| {
+                let exchange = get_contract_config(chain_id, neg_risk).unwrap().exchange;
+                let exchange = Address::from_str(&exchange).unwrap();
+                sign_order_message(&signer, order.clone(), chain_id, exchange).unwrap()
+            };
+
+            assert_eq!(signed.signature, sign_with(POLYGON_AMOY_TESTNET));
+            assert_ne!(signed.signature, sign_with(POLYGON_MAINNET));
+        }
+    }
+
+    #[test]
+    fn test_fee_rate_above_max_rejected() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let order_args = OrderArgs::new("1234", dec!(0.55), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+            .max_fee_rate_bps(100);
+
+        let result = builder.create_order(
+            137,
+            &order_args,
+            0,
+            &ExtraOrderArgs::new().fee_rate_bps(150),
+            options.clone(),
+        );
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+
+        let result = builder.create_order(
+            137,
+            &order_args,
+            0,
+            &ExtraOrderArgs::new().fee_rate_bps(100),
+            options,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_rejects_invalid_order_args() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+
+        for order in [
+            OrderArgs::new("1234", dec!(0), dec!(10), Side::Buy),
+            OrderArgs::new("1234", dec!(0.50), dec!(0), Side::Buy),
+        ] {
+            let result =
+                builder.create_order(137, &order, 0, &ExtraOrderArgs::default(), options.clone());
+            assert!(matches!(result, Err(Error::InvalidParameter(_))));
+        }
+
+        let order = MarketOrderArgs::new("1234", dec!(0), Side::Buy);
+        let result = builder.create_market_order(
+            137,
+            &order,
+            dec!(0.50),
+            &ExtraOrderArgs::default(),
+            options,
+        );
+        assert!(matches!(result, Err(Error::InvalidParameter(msg)) if msg.contains("amount")));
+    }
+
+    #[test]
+    fn test_create_order_batch() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let orders: Vec<OrderArgs> = [dec!(0.50), dec!(0.49), dec!(0.48)]
+            .into_iter()
+            .map(|price| OrderArgs::new("1234", price, dec!(10), Side::Buy))
+            .collect();
+
+        let signed = builder
+            .create_order_batch(137, &orders, 0, &ExtraOrderArgs::default(), options.clone())
+            .unwrap();
+        assert_eq!(signed.len(), 3);
+        assert_eq!(signed[1].maker_amount, "4900000");
+        let salts: HashSet<u64> = signed.iter().map(|order| order.salt).collect();
+        assert_eq!(salts.len(), 3);
+
+        // Salts stay distinct when the clock makes every seed zero
+        let zero_clock_builder = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_clock(FixedClock::from_unix_secs(0).unwrap());
+        let signed = zero_clock_builder
+            .create_order_batch(137, &orders, 0, &ExtraOrderArgs::default(), options.clone())
+            .unwrap();
+        let salts: HashSet<u64> = signed.iter().map(|order| order.salt).collect();
+        assert_eq!(salts.len(), 3);
+
+        // One bad order fails the whole batch and reports its index
+        let mut invalid = orders.clone();
+        invalid[1].token_id = "not-a-token".to_string();
+        let result =
+            builder.create_order_batch(137, &invalid, 0, &ExtraOrderArgs::default(), options);
+        assert!(matches!(
+            result,
+            Err(Error::BatchOrderFailed { index: 1, ref error })
+                if matches!(**error, Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_fok_book_rejects_unfillable_market_order() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let asks = vec![
+            PriceLevel {
+                price: dec!(0.50),
+                size: dec!(20),
+            },
+            PriceLevel {
+                price: dec!(0.60),
+                size: dec!(100),
+            },
+        ];
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+            .fok_book(asks);
+
+        // 10 USDC available at 0.50 or better
+        let order = MarketOrderArgs::new("1234", dec!(10), Side::Buy);
+        let result = builder.create_market_order(
+            137,
+            &order,
+            dec!(0.50),
+            &ExtraOrderArgs::default(),
+            options.clone(),
+        );
+        assert!(result.is_ok());
+
+        let order = MarketOrderArgs::new("1234", dec!(15), Side::Buy);
+        let result = builder.create_market_order(
+            137,
+            &order,
+            dec!(0.509),
+            &ExtraOrderArgs::default(),
+            options,
+        );
+        assert!(matches!(result, Err(Error::InvalidOrder(msg)) if msg.contains("short by 5")));
+    }
+
+    #[test]
+    fn test_market_order_by_value() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+
+        // 10 USDC at 0.30 is 33.33 shares, leaving 0.001 unspent
+        let order = MarketOrderArgs::new_by_value("1234", dec!(10), Side::Sell);
+        let size = builder
+            .market_order_size(&order, dec!(0.30), &options)
+            .unwrap();
+        assert_eq!(size.shares, dec!(33.33));
+        assert_eq!(size.unspent, dec!(0.001));
+
+        // Walking the bids: 10 shares at 0.50, then 10 at 0.30
+        let options = options.fok_book(vec![
+            PriceLevel {
+                price: dec!(0.50),
+                size: dec!(10),
+            },
+            PriceLevel {
+                price: dec!(0.30),
+                size: dec!(100),
+            },
+        ]);
+        let order = MarketOrderArgs::new_by_value("1234", dec!(8), Side::Sell);
+        let size = builder
+            .market_order_size(&order, dec!(0.30), &options)
+            .unwrap();
+        assert_eq!(size.shares, dec!(20));
+        assert_eq!(size.unspent, dec!(0));
+
+        let signed = builder
+            .create_market_order(137, &order, dec!(0.30), &ExtraOrderArgs::default(), options)
+            .unwrap();
+        assert_eq!(signed.maker_amount, "20000000");
+        assert_eq!(signed.taker_amount, "6000000");
+    }
+
+    #[test]
+    fn test_market_buy_by_value_walks_asks() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+            .fok_book(vec![
+                PriceLevel {
+                    price: dec!(0.60),
+                    size: dec!(100),
+                },
+                PriceLevel {
+                    price: dec!(0.50),
+                    size: dec!(10),
+                },
+            ]);
+
+        // 11 USDC buys 10 shares at 0.50, then 10 at 0.60; 0.005 is below the
+        // size precision and stays unspent
+        let order = MarketOrderArgs::new_by_value("1234", dec!(11.005), Side::Buy);
+        let (signed, size) = builder
+            .create_market_order_with_size(
+                137,
+                &order,
+                dec!(0.60),
+                &ExtraOrderArgs::default(),
+                options,
+            )
+            .unwrap();
+        assert_eq!(size.shares, dec!(20));
+        assert_eq!(size.unspent, dec!(0.005));
+
+        // The order spends the collateral and accepts no fewer shares than at 0.60
+        assert_eq!(signed.maker_amount, "11000000");
+        assert_eq!(signed.taker_amount, "18333300");
+    }
+
+    #[test]
+    fn test_max_slippage() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let asks = vec![
+            PriceLevel {
+                price: dec!(0.50),
+                size: dec!(10),
+            },
+            PriceLevel {
+                price: dec!(0.60),
+                size: dec!(100),
+            },
+        ];
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+            .fok_book(asks)
+            .max_slippage(dec!(0.50), 1_000);
+
+        // Fills entirely at 0.50, within 10% of the reference
+        let order = MarketOrderArgs::new("1234", dec!(5), Side::Buy);
+        let result = builder.create_market_order(
+            137,
+            &order,
+            dec!(0.50),
+            &ExtraOrderArgs::default(),
+            options.clone(),
+        );
+        assert!(result.is_ok());
+
+        // $20 buys 10 shares at 0.50 and 25 at 0.60, averaging about 0.571,
+        // above the 0.539 limit
+        let order = MarketOrderArgs::new("1234", dec!(20), Side::Buy);
+        let result = builder.create_market_order(
+            137,
+            &order,
+            dec!(0.60),
+            &ExtraOrderArgs::default(),
+            options.clone().max_slippage(dec!(0.49), 1_000),
+        );
+        assert!(matches!(result, Err(Error::InvalidOrder(msg)) if msg.contains("allowed 0.539")));
+
+        // Sells trip below the reference
+        let order = MarketOrderArgs::new("1234", dec!(10), Side::Sell);
+        let result = builder.create_market_order(
+            137,
+            &order,
+            dec!(0.44),
+            &ExtraOrderArgs::default(),
+            CreateOrderOptions::new()
+                .tick_size(dec!(0.01))
+                .neg_risk(false)
+                .max_slippage(dec!(0.50), 1_000),
+        );
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_gtd_expiration_buffer() {
+        let clock = FixedClock::from_unix_secs(1_700_000_000).unwrap();
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None).with_clock(clock);
+        let order_args = OrderArgs::new("1234", dec!(0.55), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let create = |expiration, options: CreateOrderOptions| {
+            builder.create_order(
+                137,
+                &order_args,
+                expiration,
+                &ExtraOrderArgs::default(),
+                options,
+            )
+        };
+
+        assert!(matches!(
+            create(1_700_000_030, options.clone()),
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(create(1_700_000_060, options.clone()).is_ok());
+        assert!(create(1_700_000_030, options.gtd_buffer_secs(10)).is_ok());
+    }
+
+    #[test]
+    fn test_signer_address_override() {
+        let signer = PrivateKeySigner::random();
+        let other = PrivateKeySigner::random().address();
+
+        // EOA orders must declare the signing key's own address
+        let eoa = OrderBuilder::new(signer.clone(), Some(SignatureType::Eoa), None);
+        assert!(eoa.with_signer_address(other).is_err());
+
+        let proxy = OrderBuilder::new(signer.clone(), Some(SignatureType::PolyProxy), None);
+        assert!(proxy.with_signer_address(Address::ZERO).is_err());
+
+        let proxy = OrderBuilder::new(signer, Some(SignatureType::PolyProxy), None)
+            .with_signer_address(other)
+            .unwrap();
+        let order_args = OrderArgs::new("1234", dec!(0.55), dec!(10), Side::Buy);
+        let signed = proxy
+            .create_order(
+                137,
+                &order_args,
+                0,
+                &ExtraOrderArgs::default(),
+                CreateOrderOptions::new()
+                    .tick_size(dec!(0.01))
+                    .neg_risk(false),
+            )
+            .unwrap();
+        assert_eq!(signed.signer, other.to_checksum(None));
+        assert_eq!(proxy.signer_address(), other);
+    }
 }
@@ -0,0 +1,79 @@
+use crate::types::OpenOrder;
+use crate::Side;
+use rust_decimal::Decimal;
+
+/// Funds locked by resting orders
+///
+/// Returned by [`committed_collateral`]. BUY orders lock USDC and SELL orders
+/// lock the outcome shares they offer, so the two are reported separately.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CommittedCollateral {
+    /// USDC locked by BUY orders
+    pub collateral: Decimal,
+    /// Outcome shares locked by SELL orders
+    ///
+    /// Summed across every token; pass the orders of a single asset to get the
+    /// shares committed in that outcome.
+    pub shares: Decimal,
+}
+
+/// Collateral and shares committed by resting orders
+///
+/// Only the unfilled remainder of each order (`original_size - size_matched`)
+/// is counted:
+///
+/// - BUY orders lock `price` USDC per remaining share
+/// - SELL orders lock the remaining shares themselves
+///
+/// # Arguments
+/// * `orders` - Open orders, typically from [`TradingClient::get_orders`](crate::client::TradingClient::get_orders)
+pub fn committed_collateral(orders: &[OpenOrder]) -> CommittedCollateral {
+    let mut committed = CommittedCollateral::default();
+
+    for order in orders {
+        let remaining = (order.original_size - order.size_matched).max(Decimal::ZERO);
+        match order.side {
+            Side::Buy => committed.collateral += order.price * remaining,
+            Side::Sell => committed.shares += remaining,
+        }
+    }
+
+    committed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::open_order_json;
+    use rust_decimal_macros::dec;
+
+    fn open_order(side: &str, price: &str, original_size: &str, size_matched: &str) -> OpenOrder {
+        let mut json = open_order_json("0xorder");
+        json["side"] = side.into();
+        json["price"] = price.into();
+        json["original_size"] = original_size.into();
+        json["size_matched"] = size_matched.into();
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_committed_collateral() {
+        let orders = vec![
+            // 0.40 * (100 - 25)
+            open_order("BUY", "0.40", "100", "25"),
+            // 50 - 20 shares offered
+            open_order("SELL", "0.70", "50", "20"),
+            // Fully matched orders commit nothing
+            open_order("BUY", "0.55", "10", "10"),
+        ];
+
+        assert_eq!(
+            committed_collateral(&orders),
+            CommittedCollateral {
+                collateral: dec!(30),
+                shares: dec!(30),
+            }
+        );
+        assert_eq!(committed_collateral(&[]), CommittedCollateral::default());
+    }
+}
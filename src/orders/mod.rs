@@ -1,7 +1,15 @@
 mod builder;
+mod exposure;
 mod price;
 mod rounding;
 
 pub use builder::OrderBuilder;
-pub use price::calculate_market_price;
-pub use rounding::{decimal_to_token_u64, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
+pub use exposure::{committed_collateral, CommittedCollateral};
+pub use price::{
+    calculate_market_fill, calculate_market_price, calculate_market_price_rounded,
+    calculate_market_price_with_fills, calculate_shares_for_usdc, is_valid_price,
+    market_price_curve, realized_slippage, valid_prices_between, FillLevel, FillResult, MarketFill,
+};
+#[allow(deprecated)]
+pub use rounding::decimal_to_token_u64;
+pub use rounding::{decimal_to_token_units, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
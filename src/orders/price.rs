@@ -2,18 +2,23 @@ use crate::error::{Error, Result};
 use crate::types::PriceLevel;
 use crate::Side;
 use rust_decimal::Decimal;
+use std::cmp::Reverse;
 
 /// Calculate the weighted average price for a market order based on order book depth
 ///
 /// This walks the order book until enough liquidity is found to match
 /// the requested shares, calculating the volume-weighted average price.
+/// Duplicate price levels are filled one after the other, which gives the same
+/// price as a merged level; use [`OrderBookSummary::normalize`](crate::types::OrderBookSummary::normalize)
+/// to clean up a book before inspecting its levels directly.
 ///
 /// # Arguments
 /// * `positions` - The order book positions to walk through
 /// * `shares_to_match` - The number of shares to match
 ///
 /// # Returns
-/// The weighted average price at which the market order can be filled, or an error if there's insufficient liquidity
+/// The weighted average price at which the market order can be filled, or an error if
+/// `shares_to_match` is not positive or there's insufficient liquidity
 ///
 /// # Example
 /// ```no_run
@@ -33,8 +38,133 @@ pub fn calculate_market_price(
     shares_to_match: Decimal,
     side: Side,
 ) -> Result<Decimal> {
+    calculate_market_price_with_fills(positions, shares_to_match, side).map(|fill| fill.avg_price)
+}
+
+/// Shares filled at one order book level
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillLevel {
+    pub price: Decimal,
+    pub shares: Decimal,
+}
+
+/// Breakdown of a simulated market order fill
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketFill {
+    /// Volume-weighted average price over all fills
+    pub avg_price: Decimal,
+    /// Total cost (or proceeds for a sell) of the fills
+    pub total_cost: Decimal,
+    /// Levels consumed, in the order they are filled
+    pub fills: Vec<FillLevel>,
+}
+
+/// Calculate how a market order fills against the order book
+///
+/// Walks the book like [`calculate_market_price`] but also returns the shares
+/// taken at each level, e.g. to preview a fill or measure slippage per level.
+/// Duplicate price levels appear as separate fills.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `shares_to_match` - The number of shares to match
+/// * `side` - The side of the market order
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `shares_to_match` is not positive, and
+/// [`Error::InvalidOrder`] if the book cannot fill it.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::calculate_market_price_with_fills;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let asks = vec![
+///     PriceLevel { price: dec!(0.50), size: dec!(100) },
+///     PriceLevel { price: dec!(0.52), size: dec!(200) },
+/// ];
+/// let fill = calculate_market_price_with_fills(&asks, dec!(150), Side::Buy).unwrap();
+/// assert_eq!(fill.fills.len(), 2);
+/// assert_eq!(fill.fills[1].shares, dec!(50));
+/// assert_eq!(fill.total_cost, dec!(76));
+/// ```
+pub fn calculate_market_price_with_fills(
+    positions: &[PriceLevel],
+    shares_to_match: Decimal,
+    side: Side,
+) -> Result<MarketFill> {
+    if shares_to_match <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Shares to match must be positive, got {}",
+            shares_to_match
+        )));
+    }
+
+    let fill = calculate_market_fill(positions, shares_to_match, side);
+    if !fill.remaining_shares.is_zero() {
+        return Err(Error::InvalidOrder(format!(
+            "Not enough liquidity to create market order with amount {}",
+            shares_to_match
+        )));
+    }
+
+    Ok(MarketFill {
+        avg_price: fill.avg_price,
+        total_cost: fill
+            .levels_consumed
+            .iter()
+            .map(|level| level.shares * level.price)
+            .sum(),
+        fills: fill.levels_consumed,
+    })
+}
+
+/// Outcome of walking the book for a market order, filled or not
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillResult {
+    /// Shares the book can fill, at most the requested amount
+    pub filled_shares: Decimal,
+    /// Volume-weighted average price of the filled shares, zero if nothing filled
+    pub avg_price: Decimal,
+    /// Requested shares left unfilled for lack of liquidity
+    pub remaining_shares: Decimal,
+    /// Levels consumed, in the order they are filled
+    pub levels_consumed: Vec<FillLevel>,
+}
+
+/// Walk the order book for a market order, allowing a partial fill
+///
+/// Unlike [`calculate_market_price`], insufficient liquidity is not an error:
+/// the result reports how much could be filled, at what average price, and
+/// how many shares are left over.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `shares_to_match` - The number of shares to match
+/// * `side` - The side of the market order
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::calculate_market_fill;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let asks = vec![PriceLevel { price: dec!(0.50), size: dec!(100) }];
+/// let fill = calculate_market_fill(&asks, dec!(150), Side::Buy);
+/// assert_eq!(fill.filled_shares, dec!(100));
+/// assert_eq!(fill.remaining_shares, dec!(50));
+/// ```
+pub fn calculate_market_fill(
+    positions: &[PriceLevel],
+    shares_to_match: Decimal,
+    side: Side,
+) -> FillResult {
     let mut remaining = shares_to_match;
     let mut total_cost = Decimal::ZERO;
+    let mut fills = Vec::new();
 
     // If buying, walk the asks (lowest to highest)
     // If selling, walk the bids (highest to lowest)
@@ -52,21 +182,292 @@ pub fn calculate_market_price(
     };
 
     for p in positions {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+
         let filled = remaining.min(p.size);
+        if filled <= Decimal::ZERO {
+            continue;
+        }
         total_cost += filled * p.price;
         remaining -= filled;
+        fills.push(FillLevel {
+            price: p.price,
+            shares: filled,
+        });
+    }
+
+    let filled_shares = shares_to_match - remaining;
+    FillResult {
+        filled_shares,
+        avg_price: if filled_shares.is_zero() {
+            Decimal::ZERO
+        } else {
+            total_cost / filled_shares // weighted avg price
+        },
+        remaining_shares: remaining,
+        levels_consumed: fills,
+    }
+}
 
-        if remaining.is_zero() {
-            return Ok(total_cost / shares_to_match); // weighted avg price
+/// Walk the book until `usdc_amount` of notional is consumed
+///
+/// The collateral counterpart of [`calculate_market_price`]: converts an amount
+/// to spend (or receive) into the shares it buys (or sells), accumulating
+/// `shares × price` level by level. Levels with a non-positive price are ignored.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `usdc_amount` - The collateral amount to spend (buy) or receive (sell)
+/// * `side` - The side of the market order
+///
+/// # Returns
+/// `(shares_filled, vwap)`, where `vwap` is the volume-weighted average price
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if the amount is not positive, and
+/// [`Error::InvalidOrder`] if it exceeds the available liquidity.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::calculate_shares_for_usdc;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let asks = vec![
+///     PriceLevel { price: dec!(0.50), size: dec!(100) },
+///     PriceLevel { price: dec!(0.60), size: dec!(100) },
+/// ];
+/// // $80 buys 100 shares at 0.50, then 50 at 0.60
+/// let (shares, vwap) = calculate_shares_for_usdc(&asks, dec!(80), Side::Buy).unwrap();
+/// assert_eq!(shares, dec!(150));
+/// assert_eq!(vwap, dec!(80) / dec!(150));
+/// ```
+pub fn calculate_shares_for_usdc(
+    positions: &[PriceLevel],
+    usdc_amount: Decimal,
+    side: Side,
+) -> Result<(Decimal, Decimal)> {
+    if usdc_amount <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Amount must be positive, got {}",
+            usdc_amount
+        )));
+    }
+
+    let mut levels = positions.to_vec();
+    match side {
+        Side::Buy => levels.sort_by_key(|level| level.price),
+        Side::Sell => levels.sort_by_key(|level| Reverse(level.price)),
+    }
+
+    let mut remaining = usdc_amount;
+    let mut shares = Decimal::ZERO;
+    for level in levels.iter().filter(|level| level.price > Decimal::ZERO) {
+        let cost = level.price * level.size;
+        if cost >= remaining {
+            shares += remaining / level.price;
+            return Ok((shares, usdc_amount / shares));
         }
+        shares += level.size;
+        remaining -= cost;
     }
 
     Err(Error::InvalidOrder(format!(
         "Not enough liquidity to create market order with amount {}",
-        shares_to_match
+        usdc_amount
     )))
 }
 
+/// Calculate the market price rounded to a valid tick
+///
+/// Same as [`calculate_market_price`], but the weighted average is rounded to a
+/// multiple of `tick_size` in the conservative direction: up for buys and down for
+/// sells, so the result can be used directly as a limit price that still fills.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `shares_to_match` - The number of shares to match
+/// * `side` - The side of the market order
+/// * `tick_size` - The market's minimum tick size
+pub fn calculate_market_price_rounded(
+    positions: &[PriceLevel],
+    shares_to_match: Decimal,
+    side: Side,
+    tick_size: Decimal,
+) -> Result<Decimal> {
+    if tick_size <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Invalid tick_size: {}",
+            tick_size
+        )));
+    }
+
+    let price = calculate_market_price(positions, shares_to_match, side)?;
+    let ticks = price / tick_size;
+    let ticks = match side {
+        Side::Buy => ticks.ceil(),
+        Side::Sell => ticks.floor(),
+    };
+
+    Ok((ticks * tick_size).normalize())
+}
+
+/// Check whether a price is valid at the given tick size
+///
+/// A valid price is a multiple of `tick_size` between `tick_size` and
+/// `1 - tick_size` inclusive, the range the exchange accepts for limit orders.
+/// The check is exact regardless of scale, so `0.5` and `0.50` are both valid
+/// at a tick of `0.01`. Returns false for a non-positive tick size.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::is_valid_price;
+/// use rust_decimal_macros::dec;
+///
+/// assert!(is_valid_price(dec!(0.55), dec!(0.01)));
+/// assert!(!is_valid_price(dec!(0.555), dec!(0.01)));
+/// assert!(!is_valid_price(dec!(1), dec!(0.01)));
+/// ```
+pub fn is_valid_price(price: Decimal, tick_size: Decimal) -> bool {
+    tick_size > Decimal::ZERO
+        && price >= tick_size
+        && price <= Decimal::ONE - tick_size
+        && (price % tick_size).is_zero()
+}
+
+/// List every valid price between `lo` and `hi` inclusive, in ascending order
+///
+/// Prices are the multiples of `tick_size` accepted by [`is_valid_price`], so
+/// the bounds do not need to be on a tick themselves. Returns an empty list for
+/// a non-positive tick size or when `lo > hi`.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::valid_prices_between;
+/// use rust_decimal_macros::dec;
+///
+/// let prices = valid_prices_between(dec!(0.42), dec!(0.5), dec!(0.05));
+/// assert_eq!(prices, vec![dec!(0.45), dec!(0.50)]);
+/// ```
+pub fn valid_prices_between(lo: Decimal, hi: Decimal, tick_size: Decimal) -> Vec<Decimal> {
+    if tick_size <= Decimal::ZERO || lo > hi {
+        return Vec::new();
+    }
+
+    let lo = lo.max(tick_size);
+    let hi = hi.min(Decimal::ONE - tick_size);
+
+    let mut prices = Vec::new();
+    let mut price = (lo / tick_size).ceil() * tick_size;
+    while price <= hi {
+        prices.push(price);
+        price += tick_size;
+    }
+    prices
+}
+
+/// Calculate weighted average prices for several amounts in one pass
+///
+/// Equivalent to calling [`calculate_market_price`] for every amount, but the
+/// book is sorted once and walked cumulatively. Useful for cost curves and
+/// price impact charts.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `amounts` - The share amounts to price, in any order
+/// * `side` - The side of the market order
+///
+/// # Returns
+/// `(amount, avg_price)` pairs in the same order as `amounts`, or an error if an
+/// amount is not positive or exceeds the available liquidity
+pub fn market_price_curve(
+    positions: &[PriceLevel],
+    amounts: &[Decimal],
+    side: Side,
+) -> Result<Vec<(Decimal, Decimal)>> {
+    let mut levels = positions.to_vec();
+    match side {
+        Side::Buy => levels.sort_by_key(|level| level.price),
+        Side::Sell => levels.sort_by_key(|level| Reverse(level.price)),
+    }
+
+    let mut order: Vec<usize> = (0..amounts.len()).collect();
+    order.sort_by_key(|&i| amounts[i]);
+
+    let mut curve = vec![(Decimal::ZERO, Decimal::ZERO); amounts.len()];
+    let mut level = 0;
+    // Size and cost of the levels fully consumed so far
+    let mut filled = Decimal::ZERO;
+    let mut cost = Decimal::ZERO;
+
+    for i in order {
+        let amount = amounts[i];
+        if amount <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(format!(
+                "Amount must be positive, got {}",
+                amount
+            )));
+        }
+
+        while level < levels.len() && filled + levels[level].size < amount {
+            filled += levels[level].size;
+            cost += levels[level].size * levels[level].price;
+            level += 1;
+        }
+
+        let Some(current) = levels.get(level) else {
+            return Err(Error::InvalidOrder(format!(
+                "Not enough liquidity to create market order with amount {}",
+                amount
+            )));
+        };
+
+        let total_cost = cost + (amount - filled) * current.price;
+        curve[i] = (amount, total_cost / amount);
+    }
+
+    Ok(curve)
+}
+
+/// Realized slippage of executed fills against a pre-trade quote, in basis points
+///
+/// Fills are `(price, size)` pairs, e.g. from the trade events of a market
+/// order. The result is positive when the volume-weighted fill price is worse
+/// than `quoted_price` (higher for a buy, lower for a sell) and negative when it
+/// is better. Returns zero if there are no fills, the fills have no size, or
+/// `quoted_price` is zero.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::realized_slippage;
+/// use polymarket_rs::Side;
+/// use rust_decimal_macros::dec;
+///
+/// // Quoted 0.50, filled 10 @ 0.50 and 10 @ 0.52: average 0.51, 200 bps worse
+/// let fills = [(dec!(0.50), dec!(10)), (dec!(0.52), dec!(10))];
+/// assert_eq!(realized_slippage(dec!(0.50), &fills, Side::Buy), dec!(200));
+/// ```
+pub fn realized_slippage(
+    quoted_price: Decimal,
+    fills: &[(Decimal, Decimal)],
+    side: Side,
+) -> Decimal {
+    let size: Decimal = fills.iter().map(|(_, size)| size).sum();
+    if size.is_zero() || quoted_price.is_zero() {
+        return Decimal::ZERO;
+    }
+
+    let cost: Decimal = fills.iter().map(|(price, size)| price * size).sum();
+    let diff = match side {
+        Side::Buy => cost / size - quoted_price,
+        Side::Sell => quoted_price - cost / size,
+    };
+    diff / quoted_price * Decimal::from(10_000)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,6 +497,32 @@ mod tests {
         assert_eq!(price, dec!(0.54));
     }
 
+    #[test]
+    fn test_fills_per_level() {
+        let positions = vec![
+            order(dec!(0.55), dec!(20)),
+            order(dec!(0.50), dec!(10)),
+            order(dec!(0.60), dec!(30)),
+        ];
+
+        let fill = calculate_market_price_with_fills(&positions, dec!(25), Side::Buy).unwrap();
+        assert_eq!(
+            fill.fills,
+            vec![
+                FillLevel {
+                    price: dec!(0.50),
+                    shares: dec!(10)
+                },
+                FillLevel {
+                    price: dec!(0.55),
+                    shares: dec!(15)
+                },
+            ]
+        );
+        assert_eq!(fill.total_cost, dec!(13.25));
+        assert_eq!(fill.avg_price, dec!(0.53));
+    }
+
     #[test]
     fn test_single_tick() {
         let positions = vec![order(dec!(0.50), dec!(100))];
@@ -109,4 +536,179 @@ mod tests {
         let result = calculate_market_price(&positions, dec!(20), Side::Buy);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_shares_for_usdc() {
+        let positions = vec![order(dec!(0.55), dec!(20)), order(dec!(0.50), dec!(10))];
+
+        // Buy $10: 10 shares @ 0.50 ($5), then $5 / 0.55 shares
+        let (shares, vwap) = calculate_shares_for_usdc(&positions, dec!(10), Side::Buy).unwrap();
+        assert_eq!(shares, dec!(10) + dec!(5) / dec!(0.55));
+        assert_eq!(vwap, dec!(10) / shares);
+
+        // Sell for $11: exactly the 20 shares @ 0.55
+        let (shares, vwap) = calculate_shares_for_usdc(&positions, dec!(11), Side::Sell).unwrap();
+        assert_eq!(shares, dec!(20));
+        assert_eq!(vwap, dec!(0.55));
+    }
+
+    #[test]
+    fn test_shares_for_usdc_errors() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+        assert!(matches!(
+            calculate_shares_for_usdc(&positions, dec!(6), Side::Buy),
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(matches!(
+            calculate_shares_for_usdc(&positions, dec!(0), Side::Buy),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_non_positive_shares() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+        for shares in [dec!(0), dec!(-1)] {
+            assert!(matches!(
+                calculate_market_price(&positions, shares, Side::Buy),
+                Err(Error::InvalidParameter(_))
+            ));
+            assert!(matches!(
+                calculate_market_price_with_fills(&positions, shares, Side::Sell),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_rounded_buy_rounds_up() {
+        // Weighted average 0.53 rounds up to the next 0.05 tick
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.55), dec!(20))];
+        let price =
+            calculate_market_price_rounded(&positions, dec!(25), Side::Buy, dec!(0.05)).unwrap();
+        assert_eq!(price, dec!(0.55));
+    }
+
+    #[test]
+    fn test_rounded_sell_rounds_down() {
+        // Weighted average 0.54 rounds down to the previous 0.05 tick
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.55), dec!(20))];
+        let price =
+            calculate_market_price_rounded(&positions, dec!(25), Side::Sell, dec!(0.05)).unwrap();
+        assert_eq!(price, dec!(0.50));
+    }
+
+    #[test]
+    fn test_rounded_on_tick_unchanged() {
+        let positions = vec![order(dec!(0.50), dec!(100))];
+        let price =
+            calculate_market_price_rounded(&positions, dec!(50), Side::Buy, dec!(0.01)).unwrap();
+        assert_eq!(price, dec!(0.5));
+    }
+
+    #[test]
+    fn test_is_valid_price() {
+        assert!(is_valid_price(dec!(0.5), dec!(0.01)));
+        assert!(is_valid_price(dec!(0.001), dec!(0.001)));
+        assert!(is_valid_price(dec!(0.999), dec!(0.001)));
+        assert!(!is_valid_price(dec!(0.505), dec!(0.01)));
+        assert!(!is_valid_price(dec!(0), dec!(0.01)));
+        assert!(!is_valid_price(dec!(0.995), dec!(0.01)));
+        assert!(!is_valid_price(dec!(0.5), dec!(0)));
+    }
+
+    #[test]
+    fn test_valid_prices_between() {
+        assert_eq!(
+            valid_prices_between(dec!(0), dec!(0.35), dec!(0.1)),
+            vec![dec!(0.1), dec!(0.2), dec!(0.3)]
+        );
+        assert_eq!(
+            valid_prices_between(dec!(0.85), dec!(1), dec!(0.1)),
+            vec![dec!(0.9)]
+        );
+        assert!(valid_prices_between(dec!(0.5), dec!(0.4), dec!(0.1)).is_empty());
+        assert!(valid_prices_between(dec!(0.41), dec!(0.49), dec!(0.1)).is_empty());
+    }
+
+    #[test]
+    fn test_rounded_invalid_tick_size() {
+        let positions = vec![order(dec!(0.50), dec!(100))];
+        let result = calculate_market_price_rounded(&positions, dec!(50), Side::Buy, dec!(0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_market_price_curve_matches_single_calls() {
+        let positions = vec![
+            order(dec!(0.55), dec!(20)),
+            order(dec!(0.50), dec!(10)),
+            order(dec!(0.60), dec!(30)),
+        ];
+        let amounts = [dec!(25), dec!(5), dec!(30), dec!(60)];
+
+        for side in [Side::Buy, Side::Sell] {
+            let curve = market_price_curve(&positions, &amounts, side).unwrap();
+            for (amount, (curve_amount, price)) in amounts.iter().zip(curve) {
+                assert_eq!(*amount, curve_amount);
+                assert_eq!(
+                    price,
+                    calculate_market_price(&positions, *amount, side).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_market_price_curve_errors() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+        assert!(market_price_curve(&positions, &[dec!(5), dec!(20)], Side::Buy).is_err());
+        assert!(market_price_curve(&positions, &[dec!(0)], Side::Buy).is_err());
+    }
+
+    #[test]
+    fn test_realized_slippage() {
+        let fills = [(dec!(0.48), dec!(10)), (dec!(0.52), dec!(30))];
+
+        // Average fill 0.51
+        assert_eq!(realized_slippage(dec!(0.50), &fills, Side::Buy), dec!(200));
+        assert_eq!(
+            realized_slippage(dec!(0.50), &fills, Side::Sell),
+            dec!(-200)
+        );
+        assert_eq!(realized_slippage(dec!(0.50), &[], Side::Buy), dec!(0));
+    }
+
+    #[test]
+    fn test_market_fill_exact() {
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.55), dec!(20))];
+        let fill = calculate_market_fill(&positions, dec!(30), Side::Buy);
+
+        assert_eq!(fill.filled_shares, dec!(30));
+        assert_eq!(fill.remaining_shares, dec!(0));
+        assert_eq!(fill.levels_consumed.len(), 2);
+        // (10 * 0.50 + 20 * 0.55) / 30
+        assert_eq!(fill.avg_price.round_dp(6), dec!(0.533333));
+    }
+
+    #[test]
+    fn test_market_fill_partial() {
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.55), dec!(20))];
+        let fill = calculate_market_fill(&positions, dec!(40), Side::Sell);
+
+        assert_eq!(fill.filled_shares, dec!(30));
+        assert_eq!(fill.remaining_shares, dec!(10));
+        assert_eq!(fill.levels_consumed[0].price, dec!(0.55));
+        assert!(calculate_market_price(&positions, dec!(40), Side::Sell).is_err());
+    }
+
+    #[test]
+    fn test_market_fill_empty_book() {
+        let fill = calculate_market_fill(&[], dec!(10), Side::Buy);
+
+        assert_eq!(fill.filled_shares, dec!(0));
+        assert_eq!(fill.avg_price, dec!(0));
+        assert_eq!(fill.remaining_shares, dec!(10));
+        assert!(fill.levels_consumed.is_empty());
+    }
 }
@@ -1,3 +1,4 @@
+use crate::error::{Error, Result};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::{AwayFromZero, MidpointTowardZero, ToZero};
 use std::collections::HashMap;
@@ -51,12 +52,30 @@ pub static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::
 });
 
 /// Convert decimal amount to token units (multiply by 1e6 and round)
-pub fn decimal_to_token_u64(amt: Decimal) -> u64 {
-    let mut amt = Decimal::from_scientific("1e6").expect("1e6 is not scientific") * amt;
-    if amt.scale() > 0 {
-        amt = amt.round_dp_with_strategy(0, MidpointTowardZero);
+///
+/// # Errors
+/// Returns [`Error::ArithmeticOverflow`] if the amount is negative or does not fit
+/// in a `u64` once scaled.
+pub fn decimal_to_token_units(amt: Decimal) -> Result<u64> {
+    let overflow = || Error::ArithmeticOverflow(format!("{} does not fit in token units", amt));
+
+    let mut units = Decimal::from(1_000_000)
+        .checked_mul(amt)
+        .ok_or_else(overflow)?;
+    if units.scale() > 0 {
+        units = units.round_dp_with_strategy(0, MidpointTowardZero);
     }
-    amt.try_into().expect("Couldn't round decimal to integer")
+    units.try_into().map_err(|_| overflow())
+}
+
+/// Convert decimal amount to token units (multiply by 1e6 and round)
+///
+/// # Panics
+/// Panics if the amount is negative or does not fit in a `u64` once scaled. Use
+/// [`decimal_to_token_units`] to get an error instead.
+#[deprecated(note = "use `decimal_to_token_units`, which returns an error instead of panicking")]
+pub fn decimal_to_token_u64(amt: Decimal) -> u64 {
+    decimal_to_token_units(amt).expect("Couldn't round decimal to integer")
 }
 
 /// Fix amount rounding to ensure proper precision
@@ -84,7 +103,29 @@ mod tests {
 
     #[test]
     fn test_decimal_to_token() {
-        let result = decimal_to_token_u64(Decimal::from_str("1.5").unwrap());
+        let result = decimal_to_token_units(Decimal::from_str("1.5").unwrap()).unwrap();
         assert_eq!(result, 1_500_000);
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_decimal_to_token_u64_shim() {
+        assert_eq!(
+            decimal_to_token_u64(Decimal::from_str("1.5").unwrap()),
+            1_500_000
+        );
+    }
+
+    #[test]
+    fn test_decimal_to_token_overflow() {
+        assert!(matches!(
+            decimal_to_token_units(Decimal::MAX),
+            Err(Error::ArithmeticOverflow(_))
+        ));
+        assert!(matches!(
+            decimal_to_token_units(Decimal::from(u64::MAX)),
+            Err(Error::ArithmeticOverflow(_))
+        ));
+        assert!(decimal_to_token_units(Decimal::from(-1)).is_err());
+    }
 }
@@ -1,3 +1,5 @@
+use super::QueryBuilder;
+
 /// Sort direction for activity queries
 #[derive(Debug, Clone)]
 pub enum SortDirection {
@@ -56,23 +58,29 @@ impl TradeQueryParams {
         self
     }
 
-    pub fn to_query_string(&self) -> String {
-        let mut params = Vec::new();
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(3);
 
         if let Some(limit) = self.limit {
-            params.push(format!("limit={}", limit));
+            params.push(("limit", limit.to_string()));
         }
         if let Some(offset) = self.offset {
-            params.push(format!("offset={}", offset));
+            params.push(("offset", offset.to_string()));
         }
         if let Some(taker_only) = self.taker_only {
-            params.push(format!("takerOnly={}", taker_only));
+            params.push(("takerOnly", taker_only.to_string()));
         }
 
-        if params.is_empty() {
-            String::new()
+        params
+    }
+
+    /// Convert parameters to a query string continuing an existing query (leading `&`)
+    pub fn to_query_string(&self) -> String {
+        let query = QueryBuilder::new().extend(self.to_query_params()).build();
+        if query.is_empty() {
+            query
         } else {
-            format!("&{}", params.join("&"))
+            format!("&{}", query)
         }
     }
 }
@@ -111,26 +119,70 @@ impl ActivityQueryParams {
         self
     }
 
-    pub fn to_query_string(&self) -> String {
-        let mut params = Vec::new();
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(4);
 
         if let Some(limit) = self.limit {
-            params.push(format!("limit={}", limit));
+            params.push(("limit", limit.to_string()));
         }
         if let Some(ref sort_by) = self.sort_by {
-            params.push(format!("sortBy={}", sort_by.as_str()));
+            params.push(("sortBy", sort_by.as_str().to_string()));
         }
         if let Some(ref sort_direction) = self.sort_direction {
-            params.push(format!("sortDirection={}", sort_direction.as_str()));
+            params.push(("sortDirection", sort_direction.as_str().to_string()));
         }
         if let Some(offset) = self.offset {
-            params.push(format!("offset={}", offset));
+            params.push(("offset", offset.to_string()));
         }
 
-        if params.is_empty() {
-            String::new()
+        params
+    }
+
+    /// Convert parameters to a query string continuing an existing query (leading `&`)
+    pub fn to_query_string(&self) -> String {
+        let query = QueryBuilder::new().extend(self.to_query_params()).build();
+        if query.is_empty() {
+            query
         } else {
-            format!("&{}", params.join("&"))
+            format!("&{}", query)
         }
     }
 }
+
+/// Query parameters for position endpoints
+#[derive(Debug, Clone, Default)]
+pub struct PositionParams {
+    pub market: Option<String>,
+    pub asset_id: Option<String>,
+}
+
+impl PositionParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return positions in this market (condition ID)
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    /// Only return the position in this asset (token ID)
+    pub fn with_asset_id(mut self, asset_id: impl Into<String>) -> Self {
+        self.asset_id = Some(asset_id.into());
+        self
+    }
+
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(2);
+
+        if let Some(ref market) = self.market {
+            params.push(("market", market.clone()));
+        }
+        if let Some(ref asset_id) = self.asset_id {
+            params.push(("asset_id", asset_id.clone()));
+        }
+
+        params
+    }
+}
@@ -1,3 +1,5 @@
+use super::QueryBuilder;
+
 /// Query parameters for Gamma API market endpoints
 #[derive(Debug, Clone, Default)]
 pub struct GammaMarketParams {
@@ -7,6 +9,8 @@ pub struct GammaMarketParams {
     pub closed: Option<bool>,
     pub archived: Option<bool>,
     pub tag_id: Option<String>,
+    pub tag_slug: Option<String>,
+    pub category_slug: Option<String>,
     pub order: Option<String>,
     pub ascending: Option<bool>,
 }
@@ -53,6 +57,18 @@ impl GammaMarketParams {
         self
     }
 
+    /// Filter by tag slug (e.g. "us-politics")
+    pub fn with_tag_slug(mut self, slug: impl Into<String>) -> Self {
+        self.tag_slug = Some(slug.into());
+        self
+    }
+
+    /// Filter by category slug
+    pub fn with_category_slug(mut self, slug: impl Into<String>) -> Self {
+        self.category_slug = Some(slug.into());
+        self
+    }
+
     /// Set the ordering field
     pub fn with_order(mut self, order: impl Into<String>, ascending: bool) -> Self {
         self.order = Some(order.into());
@@ -62,38 +78,116 @@ impl GammaMarketParams {
 
     /// Convert parameters to query string
     pub fn to_query_string(&self) -> String {
-        let mut params = Vec::new();
-
-        if let Some(limit) = self.limit {
-            params.push(format!("limit={}", limit));
-        }
-        if let Some(offset) = self.offset {
-            params.push(format!("offset={}", offset));
-        }
-        if let Some(active) = self.active {
-            params.push(format!("active={}", active));
-        }
-        if let Some(closed) = self.closed {
-            params.push(format!("closed={}", closed));
-        }
-        if let Some(archived) = self.archived {
-            params.push(format!("archived={}", archived));
-        }
-        if let Some(ref tag_id) = self.tag_id {
-            params.push(format!("tag_id={}", tag_id));
-        }
-        if let Some(ref order) = self.order {
-            params.push(format!("order={}", order));
-        }
-        if let Some(ascending) = self.ascending {
-            params.push(format!("ascending={}", ascending));
-        }
-
-        if params.is_empty() {
-            String::new()
-        } else {
-            format!("?{}", params.join("&"))
-        }
+        QueryBuilder::new()
+            .push_opt("limit", self.limit)
+            .push_opt("offset", self.offset)
+            .push_opt("active", self.active)
+            .push_opt("closed", self.closed)
+            .push_opt("archived", self.archived)
+            .push_opt("tag_id", self.tag_id.as_ref())
+            .push_opt("tag_slug", self.tag_slug.as_ref())
+            .push_opt("category_slug", self.category_slug.as_ref())
+            .push_opt("order", self.order.as_ref())
+            .push_opt("ascending", self.ascending)
+            .to_query_string()
+    }
+}
+
+/// Query parameters for the Gamma API events endpoint
+#[derive(Debug, Clone, Default)]
+pub struct GammaEventParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+}
+
+impl GammaEventParams {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of results to return
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the pagination offset
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Filter for active events
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Filter for closed events
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = Some(closed);
+        self
+    }
+
+    /// Set the ordering field
+    pub fn with_order(mut self, order: impl Into<String>, ascending: bool) -> Self {
+        self.order = Some(order.into());
+        self.ascending = Some(ascending);
+        self
+    }
+
+    /// Convert parameters to query string
+    pub fn to_query_string(&self) -> String {
+        QueryBuilder::new()
+            .push_opt("limit", self.limit)
+            .push_opt("offset", self.offset)
+            .push_opt("active", self.active)
+            .push_opt("closed", self.closed)
+            .push_opt("order", self.order.as_ref())
+            .push_opt("ascending", self.ascending)
+            .to_query_string()
+    }
+}
+
+/// Options for the Gamma API search endpoint
+#[derive(Debug, Clone, Default)]
+pub struct SearchParams {
+    pub limit_per_type: Option<u32>,
+    pub active_only: bool,
+}
+
+impl SearchParams {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of results of each type (markets, events, profiles)
+    pub fn with_limit_per_type(mut self, limit: u32) -> Self {
+        self.limit_per_type = Some(limit);
+        self
+    }
+
+    /// Only return active events and leave out closed markets
+    pub fn with_active_only(mut self, active_only: bool) -> Self {
+        self.active_only = active_only;
+        self
+    }
+
+    /// Convert parameters to the query string of a search for `query`
+    pub fn to_query_string(&self, query: &str) -> String {
+        QueryBuilder::new()
+            .push("q", query)
+            .push_opt("limit_per_type", self.limit_per_type)
+            .push_opt("events_status", self.active_only.then_some("active"))
+            .push_opt("keep_closed_markets", self.active_only.then_some(0))
+            .push("search_profiles", true)
+            .to_query_string()
     }
 }
 
@@ -151,4 +245,58 @@ mod tests {
         assert!(query.contains("closed=false"));
         assert!(query.contains("tag_id=politics"));
     }
+
+    #[test]
+    fn test_slug_filters() {
+        let params = GammaMarketParams::new()
+            .with_tag_slug("us-politics")
+            .with_category_slug("elections");
+
+        assert_eq!(
+            params.to_query_string(),
+            "?category_slug=elections&tag_slug=us-politics"
+        );
+    }
+
+    #[test]
+    fn test_values_are_encoded() {
+        let params = GammaMarketParams::new().with_tag_id("us politics&more");
+
+        let query = params.to_query_string();
+        assert_eq!(query, "?tag_id=us%20politics%26more");
+    }
+
+    #[test]
+    fn test_event_params_query_string() {
+        assert_eq!(GammaEventParams::new().to_query_string(), "");
+
+        let params = GammaEventParams::new()
+            .with_limit(50)
+            .with_offset(100)
+            .with_active(true)
+            .with_closed(false)
+            .with_order("startDate", true);
+
+        assert_eq!(
+            params.to_query_string(),
+            "?active=true&ascending=true&closed=false&limit=50&offset=100&order=startDate"
+        );
+    }
+
+    #[test]
+    fn test_search_query_string() {
+        assert_eq!(
+            SearchParams::new().to_query_string("trump 2028"),
+            "?q=trump%202028&search_profiles=true"
+        );
+
+        let query = SearchParams::new()
+            .with_limit_per_type(5)
+            .with_active_only(true)
+            .to_query_string("btc");
+        assert_eq!(
+            query,
+            "?events_status=active&keep_closed_markets=0&limit_per_type=5&q=btc&search_profiles=true"
+        );
+    }
 }
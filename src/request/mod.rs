@@ -1,7 +1,16 @@
 mod data_params;
 mod gamma_params;
 mod pagination;
+mod query;
 
-pub use data_params::{ActivityQueryParams, ActivitySortBy, SortDirection, TradeQueryParams};
-pub use gamma_params::GammaMarketParams;
-pub use pagination::{PaginationParams, END_CURSOR, INITIAL_CURSOR};
+pub use data_params::{
+    ActivityQueryParams, ActivitySortBy, PositionParams, SortDirection, TradeQueryParams,
+};
+pub use gamma_params::{GammaEventParams, GammaMarketParams, SearchParams};
+pub use pagination::{
+    paginate, PageResponse, Paginated, PaginationParams, END_CURSOR, INITIAL_CURSOR,
+};
+pub use query::QueryBuilder;
+
+pub(crate) use pagination::MaybePaginated;
+pub(crate) use query::encode_path_segment;
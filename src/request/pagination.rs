@@ -1,3 +1,13 @@
+use futures_util::{stream, Stream, TryStreamExt};
+use serde::Deserialize;
+use std::future::Future;
+
+use crate::error::Result;
+use crate::types::{
+    Market, MarketsResponse, OpenOrder, OpenOrdersResponse, RewardEarning, RewardEarningsResponse,
+    SimplifiedMarket, SimplifiedMarketsResponse,
+};
+
 /// Pagination cursors for API requests
 pub const END_CURSOR: &str = "LTE=";
 pub const INITIAL_CURSOR: &str = "MA==";
@@ -40,9 +50,133 @@ impl Default for PaginationParams {
     }
 }
 
+/// One page of a cursor-paginated list
+#[derive(Debug, Clone)]
+pub struct PageResponse<T> {
+    pub data: Vec<T>,
+    /// Cursor of the next page; `None`, empty or [`END_CURSOR`] on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Cursor-paginated API response
+pub trait Paginated {
+    type Item;
+
+    /// Split the response into its items and next cursor
+    fn into_page(self) -> PageResponse<Self::Item>;
+}
+
+macro_rules! impl_paginated {
+    ($($response:ty => $item:ty),* $(,)?) => {
+        $(
+            impl Paginated for $response {
+                type Item = $item;
+
+                fn into_page(self) -> PageResponse<$item> {
+                    PageResponse {
+                        data: self.data,
+                        next_cursor: self.next_cursor,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Response of an endpoint that may return either a cursor page or a plain list
+///
+/// A plain list is treated as a single, last page.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum MaybePaginated<T> {
+    Page {
+        data: Vec<T>,
+        next_cursor: Option<String>,
+    },
+    List(Vec<T>),
+}
+
+impl<T> Paginated for MaybePaginated<T> {
+    type Item = T;
+
+    fn into_page(self) -> PageResponse<T> {
+        match self {
+            MaybePaginated::Page { data, next_cursor } => PageResponse {
+                data,
+                next_cursor: next_cursor
+                    .filter(|cursor| !cursor.is_empty() && cursor != END_CURSOR),
+            },
+            MaybePaginated::List(data) => PageResponse {
+                data,
+                next_cursor: None,
+            },
+        }
+    }
+}
+
+impl_paginated!(
+    MarketsResponse => Market,
+    SimplifiedMarketsResponse => SimplifiedMarket,
+    OpenOrdersResponse => OpenOrder,
+    RewardEarningsResponse => RewardEarning,
+);
+
+/// Await a page request, if any, returning its items and the cursor of the following page
+async fn next_page<T, Fut>(page: Option<Fut>) -> Result<Option<(Vec<T>, Option<String>)>>
+where
+    Fut: Future<Output = Result<PageResponse<T>>>,
+{
+    let Some(page) = page else {
+        return Ok(None);
+    };
+    let page = page.await?;
+    let next_cursor = page
+        .next_cursor
+        .filter(|cursor| !cursor.is_empty() && cursor != END_CURSOR);
+    Ok(Some((page.data, next_cursor)))
+}
+
+/// Stream every item of a cursor-paginated list, fetching pages as needed
+///
+/// `fetch` is called with the cursor of each page, starting at `initial_cursor`,
+/// until a page has no next cursor or returns [`END_CURSOR`]. The stream ends
+/// after the first error.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::TryStreamExt;
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::request::{paginate, Paginated, INITIAL_CURSOR};
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+/// let markets: Vec<_> = paginate(INITIAL_CURSOR, |pagination| async {
+///     Ok(client.get_markets(Some(pagination)).await?.into_page())
+/// })
+/// .try_collect()
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<T, F, Fut>(initial_cursor: &str, fetch: F) -> impl Stream<Item = Result<T>>
+where
+    F: Fn(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<PageResponse<T>>>,
+{
+    let initial = Some(initial_cursor.to_string()).filter(|cursor| cursor != END_CURSOR);
+
+    stream::try_unfold(initial, move |cursor| {
+        next_page(cursor.map(|cursor| fetch(PaginationParams::with_cursor(cursor))))
+    })
+    .map_ok(|data| stream::iter(data.into_iter().map(Ok)))
+    .try_flatten()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures_util::StreamExt;
 
     #[test]
     fn test_pagination_params() {
@@ -53,4 +187,60 @@ mod tests {
         assert_eq!(query.len(), 1);
         assert_eq!(query[0].0, "next_cursor");
     }
+
+    #[test]
+    fn test_maybe_paginated() {
+        let page: MaybePaginated<u32> =
+            serde_json::from_str(r#"{"data":[1,2],"next_cursor":"MTA="}"#).unwrap();
+        let page = page.into_page();
+        assert_eq!(page.data, vec![1, 2]);
+        assert_eq!(page.next_cursor.as_deref(), Some("MTA="));
+
+        let last: MaybePaginated<u32> =
+            serde_json::from_str(r#"{"data":[3],"next_cursor":"LTE="}"#).unwrap();
+        assert_eq!(last.into_page().next_cursor, None);
+
+        let list: MaybePaginated<u32> = serde_json::from_str("[4,5]").unwrap();
+        let list = list.into_page();
+        assert_eq!(list.data, vec![4, 5]);
+        assert_eq!(list.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursors() {
+        let requested = std::sync::Mutex::new(Vec::new());
+        let items: Vec<u32> = paginate(INITIAL_CURSOR, |pagination| {
+            let cursor = pagination.next_cursor.unwrap();
+            requested.lock().unwrap().push(cursor.clone());
+            async move {
+                let (data, next_cursor) = match cursor.as_str() {
+                    INITIAL_CURSOR => (vec![1, 2], "MTA="),
+                    "MTA=" => (vec![3], END_CURSOR),
+                    _ => panic!("unexpected cursor {}", cursor),
+                };
+                Ok(PageResponse {
+                    data,
+                    next_cursor: Some(next_cursor.to_string()),
+                })
+            }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(*requested.lock().unwrap(), vec![INITIAL_CURSOR, "MTA="]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_on_error() {
+        let results: Vec<Result<u32>> = paginate(INITIAL_CURSOR, |_| async {
+            Err(crate::error::Error::Config("boom".to_string()))
+        })
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
 }
@@ -0,0 +1,177 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Characters left unescaped in query keys, values and path segments (RFC 3986 unreserved set)
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encode a single URL component
+fn encode_component(value: &str) -> String {
+    utf8_percent_encode(value, COMPONENT).to_string()
+}
+
+/// Percent-encode a value interpolated into a URL path
+///
+/// `/` is escaped too, so the value always stays a single segment.
+pub(crate) fn encode_path_segment(segment: &str) -> String {
+    encode_component(segment)
+}
+
+/// Builder for percent-encoded query strings
+///
+/// Parameters are emitted sorted by key, whatever order they were pushed in,
+/// so the same set of parameters always produces the same bytes. This keeps
+/// cache keys and L2 signatures over the request path reproducible. Repeated
+/// keys keep the order they were pushed in. `None` values from
+/// [`push_opt`](Self::push_opt) are skipped.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::request::QueryBuilder;
+///
+/// let path = QueryBuilder::new()
+///     .push("slug", "will-btc-hit-100k?")
+///     .push_opt("limit", Some(10))
+///     .push_opt::<u32>("offset", None)
+///     .append_to("/markets");
+///
+/// assert_eq!(path, "/markets?limit=10&slug=will-btc-hit-100k%3F");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilder {
+    params: Vec<(String, String)>,
+}
+
+impl QueryBuilder {
+    /// Create an empty query builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a parameter
+    pub fn push(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.params.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Add a parameter if a value is present
+    pub fn push_opt<T: ToString>(self, key: impl Into<String>, value: Option<T>) -> Self {
+        match value {
+            Some(value) => self.push(key, value),
+            None => self,
+        }
+    }
+
+    /// Add every parameter from an iterator of key/value pairs
+    pub fn extend<K, V>(mut self, params: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: ToString,
+    {
+        self.params.extend(
+            params
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.to_string())),
+        );
+        self
+    }
+
+    /// Check whether no parameters have been added
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Build the encoded `key=value` pairs joined by `&`, without a leading separator
+    ///
+    /// Pairs are sorted by key; the sort is stable so repeated keys keep their order.
+    pub fn build(&self) -> String {
+        let mut params: Vec<&(String, String)> = self.params.iter().collect();
+        params.sort_by(|a, b| a.0.cmp(&b.0));
+
+        params
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", encode_component(key), encode_component(value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Build the query string with a leading `?`, or an empty string if there are no parameters
+    pub fn to_query_string(&self) -> String {
+        if self.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", self.build())
+        }
+    }
+
+    /// Append the query to a path, using `&` if the path already has a query
+    ///
+    /// A query already present in `path` is kept as is, ahead of the sorted parameters.
+    pub fn append_to(&self, path: &str) -> String {
+        if self.is_empty() {
+            path.to_string()
+        } else if path.contains('?') {
+            format!("{}&{}", path, self.build())
+        } else {
+            format!("{}?{}", path, self.build())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_builder() {
+        let query = QueryBuilder::new().push_opt::<u32>("limit", None);
+        assert!(query.is_empty());
+        assert_eq!(query.to_query_string(), "");
+        assert_eq!(query.append_to("/markets"), "/markets");
+    }
+
+    #[test]
+    fn test_encodes_keys_and_values() {
+        let query = QueryBuilder::new()
+            .push("next_cursor", "MA==")
+            .push("q", "a b&c/d")
+            .push("slug", "us-election_2024.v2~x");
+
+        assert_eq!(
+            query.build(),
+            "next_cursor=MA%3D%3D&q=a%20b%26c%2Fd&slug=us-election_2024.v2~x"
+        );
+    }
+
+    #[test]
+    fn test_params_sorted_by_key() {
+        let query = QueryBuilder::new()
+            .push("offset", 20)
+            .push("id", "b")
+            .push("limit", 10)
+            .push("id", "a");
+
+        assert_eq!(query.build(), "id=b&id=a&limit=10&offset=20");
+    }
+
+    #[test]
+    fn test_encode_path_segment() {
+        assert_eq!(encode_path_segment("0xabc123"), "0xabc123");
+        assert_eq!(
+            encode_path_segment("fed-rates/march?"),
+            "fed-rates%2Fmarch%3F"
+        );
+    }
+
+    #[test]
+    fn test_append_to_existing_query() {
+        let query = QueryBuilder::new().push("limit", 5);
+        assert_eq!(
+            query.append_to("/trades?user=0xabc"),
+            "/trades?user=0xabc&limit=5"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::error::Result;
 use alloy_primitives::{hex::encode_prefixed, Address, U256};
-use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolStruct};
 
 // EIP-712 struct for CLOB authentication
 sol! {
@@ -66,6 +66,23 @@ where
     Ok(encode_prefixed(signature.as_bytes()))
 }
 
+/// Build the EIP-712 domain used to sign orders
+///
+/// Useful for comparing against the exchange contract's on-chain domain when
+/// debugging rejected signatures.
+///
+/// # Arguments
+/// * `chain_id` - The chain ID the order is signed for
+/// * `exchange` - The exchange contract address (the verifying contract)
+pub fn order_domain(chain_id: u64, exchange: Address) -> Eip712Domain {
+    eip712_domain!(
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: chain_id,
+        verifying_contract: exchange,
+    )
+}
+
 /// Signs an order using EIP-712
 ///
 /// This creates the signature for a limit or market order
@@ -79,12 +96,7 @@ pub fn sign_order_message<T>(
 where
     T: alloy_signer::Signer + alloy_signer::SignerSync,
 {
-    let domain = eip712_domain!(
-        name: "Polymarket CTF Exchange",
-        version: "1",
-        chain_id: chain_id,
-        verifying_contract: verifying_contract,
-    );
+    let domain = order_domain(chain_id, verifying_contract);
 
     let hash = order.eip712_signing_hash(&domain);
     let signature = signer
@@ -93,3 +105,24 @@ where
 
     Ok(encode_prefixed(signature.as_bytes()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_order_domain() {
+        let exchange = Address::from_str("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E").unwrap();
+        let domain = order_domain(137, exchange);
+
+        assert_eq!(domain.name.as_deref(), Some("Polymarket CTF Exchange"));
+        assert_eq!(domain.version.as_deref(), Some("1"));
+        assert_eq!(domain.chain_id, Some(U256::from(137)));
+        assert_eq!(domain.verifying_contract, Some(exchange));
+        assert_ne!(
+            domain.separator(),
+            order_domain(80002, exchange).separator()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod eip712;
 mod signer;
 
-pub use eip712::{sign_clob_auth_message, sign_order_message, ClobAuth, Order};
+pub use alloy_sol_types::Eip712Domain;
+pub use eip712::{order_domain, sign_clob_auth_message, sign_order_message, ClobAuth, Order};
 pub use signer::EthSigner;
@@ -1,4 +1,7 @@
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Asset type for balance and allowance operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -9,7 +12,7 @@ pub enum AssetType {
 }
 
 /// Order side (BUY or SELL)
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     #[default]
@@ -44,6 +47,35 @@ impl Side {
             _ => None,
         }
     }
+
+    /// Get the contra side (SELL for BUY and vice versa)
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Side {
+    type Err = Error;
+
+    /// Parse "BUY" or "SELL", ignoring case
+    fn from_str(s: &str) -> Result<Self> {
+        if s.eq_ignore_ascii_case("BUY") {
+            Ok(Side::Buy)
+        } else if s.eq_ignore_ascii_case("SELL") {
+            Ok(Side::Sell)
+        } else {
+            Err(Error::InvalidParameter(format!("Invalid side: {}", s)))
+        }
+    }
 }
 
 /// Order type
@@ -64,6 +96,94 @@ pub enum OrderType {
     Gtd,
 }
 
+impl OrderType {
+    /// Convert to the string used by the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Gtc => "GTC",
+            OrderType::Fok => "FOK",
+            OrderType::Fak => "FAK",
+            OrderType::Gtd => "GTD",
+        }
+    }
+
+    /// Whether orders of this type must carry a non-zero expiration
+    ///
+    /// Only GTD orders expire; every other type must use an expiration of 0.
+    pub fn requires_expiration(&self) -> bool {
+        matches!(self, OrderType::Gtd)
+    }
+
+    /// Check that an expiration timestamp is valid for this order type
+    ///
+    /// # Arguments
+    /// * `expiration` - The order expiration (Unix seconds, 0 for none)
+    /// * `now` - The current Unix time in seconds
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] if a GTD order does not expire in the future,
+    /// or if any other order type has a non-zero expiration.
+    pub fn validate_expiration(&self, expiration: u64, now: u64) -> Result<()> {
+        self.validate_expiration_with_buffer(expiration, now, 0)
+    }
+
+    /// Check an expiration like [`validate_expiration`](Self::validate_expiration),
+    /// additionally requiring GTD orders to expire at least `buffer_secs` from now
+    ///
+    /// The exchange rejects GTD orders expiring within about a minute; see
+    /// [`CreateOrderOptions::DEFAULT_GTD_BUFFER_SECS`](crate::types::CreateOrderOptions::DEFAULT_GTD_BUFFER_SECS).
+    pub fn validate_expiration_with_buffer(
+        &self,
+        expiration: u64,
+        now: u64,
+        buffer_secs: u64,
+    ) -> Result<()> {
+        if self.requires_expiration() {
+            if expiration <= now {
+                return Err(Error::InvalidOrder(format!(
+                    "{:?} order expiration {} must be in the future (now {})",
+                    self, expiration, now
+                )));
+            }
+            if expiration - now < buffer_secs {
+                return Err(Error::InvalidOrder(format!(
+                    "{:?} order expiration {} must be at least {}s in the future (now {})",
+                    self, expiration, buffer_secs, now
+                )));
+            }
+        } else if expiration != 0 {
+            return Err(Error::InvalidOrder(format!(
+                "{:?} orders must have an expiration of 0, got {}",
+                self, expiration
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OrderType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "GTC" => Ok(OrderType::Gtc),
+            "FOK" => Ok(OrderType::Fok),
+            "FAK" => Ok(OrderType::Fak),
+            "GTD" => Ok(OrderType::Gtd),
+            _ => Err(Error::InvalidParameter(format!(
+                "Invalid order type: {}",
+                s
+            ))),
+        }
+    }
+}
+
 /// Signature type for orders
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignatureType {
@@ -79,6 +199,15 @@ pub enum SignatureType {
 }
 
 impl SignatureType {
+    /// Convert to the string used by the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignatureType::Eoa => "0",
+            SignatureType::PolyProxy => "1",
+            SignatureType::PolyGnosisSafe => "2",
+        }
+    }
+
     pub fn to_u8(self) -> u8 {
         self as u8
     }
@@ -93,6 +222,28 @@ impl SignatureType {
     }
 }
 
+impl fmt::Display for SignatureType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SignatureType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "0" => Ok(SignatureType::Eoa),
+            "1" => Ok(SignatureType::PolyProxy),
+            "2" => Ok(SignatureType::PolyGnosisSafe),
+            _ => Err(Error::InvalidParameter(format!(
+                "Invalid signature type: {}",
+                s
+            ))),
+        }
+    }
+}
+
 /// Market status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -102,6 +253,39 @@ pub enum MarketStatus {
     Archived,
 }
 
+impl MarketStatus {
+    /// Convert to the string used by the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketStatus::Active => "active",
+            MarketStatus::Closed => "closed",
+            MarketStatus::Archived => "archived",
+        }
+    }
+}
+
+impl fmt::Display for MarketStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MarketStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "active" => Ok(MarketStatus::Active),
+            "closed" => Ok(MarketStatus::Closed),
+            "archived" => Ok(MarketStatus::Archived),
+            _ => Err(Error::InvalidParameter(format!(
+                "Invalid market status: {}",
+                s
+            ))),
+        }
+    }
+}
+
 /// Order status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -112,6 +296,41 @@ pub enum OrderStatus {
     Expired,
 }
 
+impl OrderStatus {
+    /// Convert to the string used by the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Live => "LIVE",
+            OrderStatus::Matched => "MATCHED",
+            OrderStatus::Canceled => "CANCELED",
+            OrderStatus::Expired => "EXPIRED",
+        }
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OrderStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "LIVE" => Ok(OrderStatus::Live),
+            "MATCHED" => Ok(OrderStatus::Matched),
+            "CANCELED" => Ok(OrderStatus::Canceled),
+            "EXPIRED" => Ok(OrderStatus::Expired),
+            _ => Err(Error::InvalidParameter(format!(
+                "Invalid order status: {}",
+                s
+            ))),
+        }
+    }
+}
+
 /// Notification type
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -124,7 +343,10 @@ pub enum NotificationType {
 }
 
 /// Activity type
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Types not known to this crate deserialize to [`ActivityType::Other`] with the
+/// raw value, so new server-side types don't break deserialization.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ActivityType {
     #[default]
@@ -135,4 +357,166 @@ pub enum ActivityType {
     Merge,
     Conversion,
     Redeem,
+    /// Activity type not known to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl ActivityType {
+    /// Convert to the string used by the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            ActivityType::Trade => "TRADE",
+            ActivityType::Yield => "YIELD",
+            ActivityType::Reward => "REWARD",
+            ActivityType::Split => "SPLIT",
+            ActivityType::Merge => "MERGE",
+            ActivityType::Conversion => "CONVERSION",
+            ActivityType::Redeem => "REDEEM",
+            ActivityType::Other(other) => other,
+        }
+    }
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ActivityType {
+    type Err = Error;
+
+    /// Parse an activity type; unknown non-empty values become [`ActivityType::Other`]
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "TRADE" => Ok(ActivityType::Trade),
+            "YIELD" => Ok(ActivityType::Yield),
+            "REWARD" => Ok(ActivityType::Reward),
+            "SPLIT" => Ok(ActivityType::Split),
+            "MERGE" => Ok(ActivityType::Merge),
+            "CONVERSION" => Ok(ActivityType::Conversion),
+            "REDEEM" => Ok(ActivityType::Redeem),
+            "" => Err(Error::InvalidParameter(
+                "Invalid activity type: empty".to_string(),
+            )),
+            other => Ok(ActivityType::Other(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_expiration() {
+        assert!(OrderType::Gtd.requires_expiration());
+        assert!(!OrderType::Gtc.requires_expiration());
+        assert!(!OrderType::Fok.requires_expiration());
+        assert!(!OrderType::Fak.requires_expiration());
+    }
+
+    #[test]
+    fn test_validate_expiration() {
+        let now = 1_700_000_000;
+
+        assert!(OrderType::Gtc.validate_expiration(0, now).is_ok());
+        assert!(OrderType::Fok.validate_expiration(now + 60, now).is_err());
+
+        assert!(OrderType::Gtd.validate_expiration(now + 60, now).is_ok());
+        assert!(OrderType::Gtd.validate_expiration(now, now).is_err());
+        assert!(OrderType::Gtd.validate_expiration(0, now).is_err());
+    }
+
+    #[test]
+    fn test_validate_expiration_with_buffer() {
+        let now = 1_700_000_000;
+
+        assert!(OrderType::Gtd
+            .validate_expiration_with_buffer(now + 60, now, 60)
+            .is_ok());
+        assert!(OrderType::Gtd
+            .validate_expiration_with_buffer(now + 59, now, 60)
+            .is_err());
+        assert!(OrderType::Gtc
+            .validate_expiration_with_buffer(0, now, 60)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_side_parse_display_opposite() {
+        assert_eq!("buy".parse::<Side>().unwrap(), Side::Buy);
+        assert_eq!("SELL".parse::<Side>().unwrap(), Side::Sell);
+        assert!(matches!(
+            "hold".parse::<Side>(),
+            Err(Error::InvalidParameter(_))
+        ));
+
+        assert_eq!(Side::Sell.to_string(), "SELL");
+        assert_eq!(Side::Buy.opposite(), Side::Sell);
+        assert_eq!(Side::Sell.opposite(), Side::Buy);
+    }
+
+    #[test]
+    fn test_enum_string_round_trip() {
+        for order_type in [
+            OrderType::Gtc,
+            OrderType::Fok,
+            OrderType::Fak,
+            OrderType::Gtd,
+        ] {
+            assert_eq!(
+                order_type.to_string().parse::<OrderType>().unwrap(),
+                order_type
+            );
+            assert_eq!(
+                serde_json::to_string(&order_type).unwrap(),
+                format!("\"{}\"", order_type)
+            );
+        }
+        assert_eq!("GTC".parse::<OrderType>().unwrap(), OrderType::Gtc);
+        assert!(matches!(
+            "gtc".parse::<OrderType>(),
+            Err(Error::InvalidParameter(_))
+        ));
+
+        assert_eq!(
+            "2".parse::<SignatureType>().unwrap(),
+            SignatureType::PolyGnosisSafe
+        );
+        assert_eq!(MarketStatus::Closed.to_string(), "closed");
+        assert_eq!(
+            "MATCHED".parse::<OrderStatus>().unwrap(),
+            OrderStatus::Matched
+        );
+        assert_eq!(
+            "CONVERSION".parse::<ActivityType>().unwrap(),
+            ActivityType::Conversion
+        );
+    }
+
+    #[test]
+    fn test_activity_type_deserialize() {
+        for (raw, expected) in [
+            ("TRADE", ActivityType::Trade),
+            ("YIELD", ActivityType::Yield),
+            ("REWARD", ActivityType::Reward),
+            ("SPLIT", ActivityType::Split),
+            ("MERGE", ActivityType::Merge),
+            ("CONVERSION", ActivityType::Conversion),
+            ("REDEEM", ActivityType::Redeem),
+            (
+                "MAKER_REBATE",
+                ActivityType::Other("MAKER_REBATE".to_string()),
+            ),
+        ] {
+            let json = format!("\"{}\"", raw);
+            let activity_type: ActivityType = serde_json::from_str(&json).unwrap();
+            assert_eq!(activity_type, expected);
+            assert_eq!(serde_json::to_string(&activity_type).unwrap(), json);
+            assert_eq!(raw.parse::<ActivityType>().unwrap(), expected);
+        }
+        assert!("".parse::<ActivityType>().is_err());
+    }
 }
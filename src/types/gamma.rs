@@ -1,6 +1,11 @@
 use chrono::{DateTime, Utc};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use super::OrderContext;
+use crate::error::{Error, Result};
+
 /// Gamma API market with rich metadata
 /// Note: Most fields are optional since the API has inconsistent data
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,11 +46,101 @@ pub struct GammaMarket {
     pub best_bid: Option<f64>,
     pub best_ask: Option<f64>,
     pub spread: Option<f64>,
+
+    // Order book settings
+    pub order_price_min_tick_size: Option<f64>,
+    pub order_min_size: Option<f64>,
+    pub neg_risk: Option<bool>,
+
     // Nested data
     #[serde(default)]
     pub events: Vec<GammaSimplifiedEvent>,
 }
 
+impl GammaMarket {
+    /// Build the context needed to create orders on this market
+    ///
+    /// Extracts the condition ID, CLOB token IDs, outcomes, tick size and
+    /// neg-risk flag from the Gamma metadata so orders can be built without a
+    /// second fetch from the CLOB API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingField`] if the token IDs, tick size or neg-risk flag
+    /// are absent, and [`Error::Json`] if the stringified token IDs or outcomes are malformed.
+    pub fn to_order_context(&self) -> Result<OrderContext> {
+        let token_ids = self.token_ids()?;
+        let outcomes = parse_json_string_array(&self.outcomes, "outcomes")?;
+
+        let tick_size = self
+            .order_price_min_tick_size
+            .and_then(Decimal::from_f64)
+            .ok_or_else(|| Error::MissingField("orderPriceMinTickSize".to_string()))?;
+
+        let neg_risk = self
+            .neg_risk
+            .ok_or_else(|| Error::MissingField("negRisk".to_string()))?;
+
+        Ok(OrderContext {
+            condition_id: self.condition_id.clone(),
+            token_ids,
+            outcomes,
+            tick_size,
+            neg_risk,
+        })
+    }
+
+    /// Get the CLOB token IDs, one per outcome
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingField`] if the token IDs are absent and
+    /// [`Error::Json`] if the stringified array is malformed.
+    pub fn token_ids(&self) -> Result<Vec<String>> {
+        parse_json_string_array(&self.clob_token_ids, "clobTokenIds")
+    }
+
+    /// Get the price of each outcome, paired with its name
+    ///
+    /// Works for any number of outcomes. On a categorical market each price is
+    /// that outcome's implied probability; unlike a binary market, one price is
+    /// not `1 -` another, so use [`OrderContext::is_binary`] before deriving a
+    /// complement.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingField`] if the outcomes or prices are absent,
+    /// [`Error::Json`] or [`Error::Decimal`] if they are malformed, and
+    /// [`Error::InvalidParameter`] if their lengths differ.
+    pub fn outcome_prices(&self) -> Result<Vec<(String, Decimal)>> {
+        let outcomes = parse_json_string_array(&self.outcomes, "outcomes")?;
+        let prices = parse_json_string_array(&self.outcome_prices, "outcomePrices")?;
+
+        if outcomes.len() != prices.len() {
+            return Err(Error::InvalidParameter(format!(
+                "Market {} has {} outcomes but {} prices",
+                self.id,
+                outcomes.len(),
+                prices.len()
+            )));
+        }
+
+        outcomes
+            .into_iter()
+            .zip(prices)
+            .map(|(outcome, price)| Ok((outcome, price.parse::<Decimal>()?)))
+            .collect()
+    }
+}
+
+/// Parse one of the Gamma fields that hold a JSON array encoded as a string
+fn parse_json_string_array(value: &Option<String>, field: &str) -> Result<Vec<String>> {
+    let value = value
+        .as_deref()
+        .ok_or_else(|| Error::MissingField(field.to_string()))?;
+    Ok(serde_json::from_str(value)?)
+}
+
 /// Event associated with a market
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -243,3 +338,177 @@ pub struct GammaSeries {
     #[serde(default)]
     pub events: Vec<GammaSimplifiedEvent>,
 }
+
+/// Public profile of a Polymarket user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub id: String,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+    pub bio: Option<String>,
+    pub profile_image: Option<String>,
+    pub proxy_wallet: Option<String>,
+    #[serde(default)]
+    pub display_username_public: bool,
+}
+
+/// Results of a free-text search on the Gamma API
+///
+/// Markets matching the query are usually returned nested in their event's
+/// `markets` rather than in `markets`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchResults {
+    #[serde(default)]
+    pub markets: Vec<GammaMarket>,
+    #[serde(default)]
+    pub events: Vec<GammaEvent>,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn market_json() -> serde_json::Value {
+        serde_json::json!({
+            "id": "646091",
+            "question": "Will it rain tomorrow?",
+            "description": "Test market",
+            "conditionId": "0xabc",
+            "slug": "will-it-rain-tomorrow",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "orderPriceMinTickSize": 0.01,
+            "orderMinSize": 5,
+            "negRisk": false
+        })
+    }
+
+    #[test]
+    fn test_to_order_context() {
+        let market: GammaMarket = serde_json::from_value(market_json()).unwrap();
+        let context = market.to_order_context().unwrap();
+
+        assert_eq!(context.condition_id, "0xabc");
+        assert_eq!(context.token_ids, vec!["111", "222"]);
+        assert_eq!(context.outcomes, vec!["Yes", "No"]);
+        assert_eq!(context.tick_size, dec!(0.01));
+        assert!(!context.neg_risk);
+        assert_eq!(context.complement_token_id("111").unwrap(), "222");
+        assert!(matches!(
+            context.complement_token_id("333"),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_order_context_missing_tick_size() {
+        let mut json = market_json();
+        json.as_object_mut()
+            .unwrap()
+            .remove("orderPriceMinTickSize");
+        let market: GammaMarket = serde_json::from_value(json).unwrap();
+
+        let result = market.to_order_context();
+        assert!(
+            matches!(result, Err(Error::MissingField(field)) if field == "orderPriceMinTickSize")
+        );
+    }
+
+    #[test]
+    fn test_token_ids() {
+        let market: GammaMarket = serde_json::from_value(market_json()).unwrap();
+        assert_eq!(market.token_ids().unwrap(), vec!["111", "222"]);
+
+        let mut json = market_json();
+        let object = json.as_object_mut().unwrap();
+        object.insert("clobTokenIds".into(), "[\"111\",".into());
+        let market: GammaMarket = serde_json::from_value(json.clone()).unwrap();
+        assert!(matches!(market.token_ids(), Err(Error::Json(_))));
+
+        json.as_object_mut().unwrap().remove("clobTokenIds");
+        let market: GammaMarket = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            market.token_ids(),
+            Err(Error::MissingField(field)) if field == "clobTokenIds"
+        ));
+    }
+
+    #[test]
+    fn test_outcome_prices_categorical() {
+        let mut json = market_json();
+        let object = json.as_object_mut().unwrap();
+        object.insert("outcomes".into(), "[\"A\", \"B\", \"C\"]".into());
+        object.insert("outcomePrices".into(), "[\"0.5\", \"0.3\", \"0.2\"]".into());
+        object.insert("clobTokenIds".into(), "[\"1\", \"2\", \"3\"]".into());
+        let market: GammaMarket = serde_json::from_value(json).unwrap();
+
+        let prices = market.outcome_prices().unwrap();
+        assert_eq!(
+            prices,
+            vec![
+                ("A".to_string(), dec!(0.5)),
+                ("B".to_string(), dec!(0.3)),
+                ("C".to_string(), dec!(0.2)),
+            ]
+        );
+
+        let context = market.to_order_context().unwrap();
+        assert!(!context.is_binary());
+        assert!(matches!(
+            context.complement_token_id("1"),
+            Err(Error::UnsupportedMarket(_))
+        ));
+    }
+
+    #[test]
+    fn test_search_results_deserialization() {
+        let json = r#"{
+            "events": [{
+                "id": "16167",
+                "ticker": "microstrategy-sell-any-bitcoin-in-2025",
+                "slug": "microstrategy-sell-any-bitcoin-in-2025",
+                "title": "MicroStrategy sells any Bitcoin in 2025?",
+                "endDate": "2025-12-31T12:00:00Z",
+                "active": true,
+                "closed": false,
+                "negRisk": false,
+                "volume": 1500000.5,
+                "markets": [{
+                    "id": "516926",
+                    "question": "MicroStrategy sells any Bitcoin in 2025?",
+                    "description": "Resolves Yes if MicroStrategy sells any Bitcoin.",
+                    "conditionId": "0x19ee98e348c0ccb341d1b9566fa14521566e9b2ea7aed34dc407a0ec56be36a2",
+                    "slug": "microstrategy-sell-any-bitcoin-in-2025",
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"0.0125\", \"0.9875\"]",
+                    "clobTokenIds": "[\"1111\", \"2222\"]",
+                    "active": true,
+                    "closed": false
+                }]
+            }],
+            "tags": [{"id": "235", "label": "Bitcoin", "slug": "bitcoin"}],
+            "profiles": [{
+                "id": "42",
+                "name": "satoshi",
+                "pseudonym": "Careful-Trader",
+                "profileImage": null,
+                "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+                "displayUsernamePublic": true
+            }],
+            "pagination": {"hasMore": false, "totalResults": 1}
+        }"#;
+
+        let results: SearchResults = serde_json::from_str(json).unwrap();
+        assert!(results.markets.is_empty());
+        assert_eq!(results.events.len(), 1);
+        assert_eq!(results.events[0].markets.len(), 1);
+        assert_eq!(results.events[0].markets[0].id, "516926");
+        assert_eq!(results.profiles.len(), 1);
+        assert_eq!(results.profiles[0].name.as_deref(), Some("satoshi"));
+        assert!(results.profiles[0].display_username_public);
+    }
+}
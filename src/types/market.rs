@@ -1,8 +1,17 @@
-use chrono::{DateTime, TimeDelta, Utc};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use super::order::OrderBookSummary;
+use crate::clock::{Clock, SystemClock};
+use crate::error::{Error, Result};
+
 /// Full market information
+///
+/// CLOB markets are always binary: categorical events are listed as a group of
+/// Yes/No markets sharing a `neg_risk_market_id`, so `tokens` holds exactly two
+/// entries. Helpers taking a `Market` may rely on that, while Gamma data can
+/// describe any number of outcomes (see [`GammaMarket::outcome_prices`](super::GammaMarket::outcome_prices)).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
     pub condition_id: String,
@@ -41,13 +50,97 @@ impl Market {
     /// Returns true if the market ends within the specified time period from now.
     /// Returns true if there's no end date (perpetual market).
     pub fn ends_within(&self, time_delta: TimeDelta) -> bool {
+        self.ends_within_with_clock(time_delta, &SystemClock)
+    }
+
+    /// Same as [`ends_within`](Self::ends_within), reading the current time from `clock`
+    pub fn ends_within_with_clock(&self, time_delta: TimeDelta, clock: &dyn Clock) -> bool {
         if let Some(end_date) = &self.end_date_iso {
-            let now = Utc::now();
+            let now = clock.now();
             let target_date = now + time_delta;
             return end_date <= &target_date;
         }
         true
     }
+
+    /// How long the market has been accepting orders
+    ///
+    /// Returns `None` if the market is not accepting orders, has no
+    /// `accepting_order_timestamp`, or only starts accepting in the future.
+    pub fn accepting_since(&self) -> Option<TimeDelta> {
+        self.accepting_since_with_clock(&SystemClock)
+    }
+
+    /// Same as [`accepting_since`](Self::accepting_since), reading the current time from `clock`
+    pub fn accepting_since_with_clock(&self, clock: &dyn Clock) -> Option<TimeDelta> {
+        if !self.accepting_orders {
+            return None;
+        }
+
+        let elapsed = clock.now() - self.accepting_order_timestamp?;
+        (elapsed >= TimeDelta::zero()).then_some(elapsed)
+    }
+
+    /// Check that the market is currently accepting orders
+    ///
+    /// Returns [`Error::MarketNotAcceptingOrders`] if `accepting_orders` is false
+    /// or `accepting_order_timestamp` is still in the future. Use this before
+    /// signing an order to avoid a guaranteed rejection on paused or closed markets.
+    pub fn ensure_accepting_orders(&self) -> Result<()> {
+        self.ensure_accepting_orders_with_clock(&SystemClock)
+    }
+
+    /// Same as [`ensure_accepting_orders`](Self::ensure_accepting_orders), reading the
+    /// current time from `clock`
+    pub fn ensure_accepting_orders_with_clock(&self, clock: &dyn Clock) -> Result<()> {
+        let started = match self.accepting_order_timestamp {
+            Some(timestamp) => timestamp <= clock.now(),
+            None => true,
+        };
+
+        if self.accepting_orders && started {
+            Ok(())
+        } else {
+            Err(Error::MarketNotAcceptingOrders(self.condition_id.clone()))
+        }
+    }
+
+    /// Check whether orders can be submitted to this market, based on its flags
+    ///
+    /// Every flag gating order submission is checked here, so new ones only need
+    /// to be added in one place:
+    /// - `active`: the market is live
+    /// - `closed`: the market has stopped trading
+    /// - `archived`: the market has been archived
+    /// - `accepting_orders`: the order book currently accepts orders
+    /// - `enable_order_book`: the market trades on the CLOB
+    ///
+    /// Use [`is_tradeable_at`](Self::is_tradeable_at) to also check `accepting_order_timestamp`.
+    pub fn is_tradeable(&self) -> bool {
+        self.active
+            && !self.closed
+            && !self.archived
+            && self.accepting_orders
+            && self.enable_order_book
+    }
+
+    /// Same as [`is_tradeable`](Self::is_tradeable), also requiring that the market
+    /// started accepting orders at or before `timestamp`
+    ///
+    /// A market without `accepting_order_timestamp` only depends on its flags.
+    pub fn is_tradeable_at(&self, timestamp: DateTime<Utc>) -> bool {
+        self.is_tradeable()
+            && self
+                .accepting_order_timestamp
+                .is_none_or(|accepting| accepting <= timestamp)
+    }
+
+    /// Find the token for an outcome name (case-insensitive, e.g. "Yes" or "No")
+    pub fn token_for_outcome(&self, outcome: &str) -> Option<&Token> {
+        self.tokens
+            .iter()
+            .find(|token| token.outcome.eq_ignore_ascii_case(outcome))
+    }
 }
 
 /// Simplified market information
@@ -79,6 +172,40 @@ pub struct Rewards {
     pub max_spread: Decimal,
 }
 
+impl Rewards {
+    /// Daily reward rate paid in the given asset
+    ///
+    /// Addresses are compared case-insensitively. Returns `None` if the market
+    /// has no rate for that asset.
+    pub fn daily_rate_for(&self, asset_address: &str) -> Option<Decimal> {
+        self.rates
+            .iter()
+            .flatten()
+            .filter(|rate| rate.asset_address.eq_ignore_ascii_case(asset_address))
+            .map(|rate| rate.rewards_daily_rate)
+            .reduce(|total, rate| total + rate)
+    }
+
+    /// Sum of the daily reward rates across all assets
+    pub fn total_daily_rate(&self) -> Decimal {
+        self.rates
+            .iter()
+            .flatten()
+            .map(|rate| rate.rewards_daily_rate)
+            .sum()
+    }
+
+    /// Check whether the market has a rewards program paying out
+    ///
+    /// True when at least one rate is present and positive.
+    pub fn is_active(&self) -> bool {
+        self.rates
+            .iter()
+            .flatten()
+            .any(|rate| rate.rewards_daily_rate > Decimal::ZERO)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RewardsRates {
     pub asset_address: String,
@@ -86,6 +213,29 @@ pub struct RewardsRates {
     pub rewards_daily_rate: Decimal,
 }
 
+/// Reward earnings accrued by a user on a market for a given day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardEarning {
+    /// Market condition ID
+    #[serde(rename = "condition_id")]
+    pub market: String,
+    /// Asset address the rewards are paid in
+    #[serde(rename = "asset_address")]
+    pub asset_id: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub earnings: Decimal,
+    pub date: NaiveDate,
+}
+
+/// Paginated reward earnings response
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RewardEarningsResponse {
+    pub limit: u64,
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<RewardEarning>,
+}
+
 /// Paginated markets response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MarketsResponse {
@@ -150,6 +300,89 @@ pub struct TickSizeResponse {
     pub minimum_tick_size: Decimal,
 }
 
+/// Top-of-book metrics for a token
+///
+/// A lighter alternative to fetching the midpoint, spread and tick size separately,
+/// returned by [`ClobClient::get_book_metrics`](crate::client::ClobClient::get_book_metrics).
+/// Price fields are `None` when the corresponding side of the book is empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookMetrics {
+    pub midpoint: Option<Decimal>,
+    pub spread: Option<Decimal>,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    pub tick_size: Decimal,
+}
+
+impl BookMetrics {
+    /// Derive the metrics from an order book and the token's tick size
+    pub fn from_book(book: &OrderBookSummary, tick_size: Decimal) -> Self {
+        Self {
+            midpoint: book.mid_price(),
+            spread: book.spread(),
+            best_bid: book.best_bid().map(|level| level.price),
+            best_ask: book.best_ask().map(|level| level.price),
+            tick_size,
+        }
+    }
+}
+
+/// Best prices for every outcome of a market
+///
+/// Returned by [`ClobClient::get_ticker`](crate::client::ClobClient::get_ticker).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ticker {
+    pub condition_id: String,
+    /// One entry per market token, in the market's token order
+    pub outcomes: Vec<OutcomeTicker>,
+}
+
+/// Best prices for one outcome token
+///
+/// Price fields are `None` when the corresponding side of the book is empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutcomeTicker {
+    pub token_id: String,
+    pub outcome: String,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    pub mid: Option<Decimal>,
+}
+
+impl Ticker {
+    /// Build the ticker of a market from the order books of its tokens
+    ///
+    /// Books are matched to tokens by asset ID; a token without a book gets no prices.
+    pub fn from_books(
+        condition_id: impl Into<String>,
+        tokens: &[Token],
+        books: &[OrderBookSummary],
+    ) -> Self {
+        let outcomes = tokens
+            .iter()
+            .map(|token| {
+                let book = books.iter().find(|book| book.asset_id == token.token_id);
+                OutcomeTicker {
+                    token_id: token.token_id.clone(),
+                    outcome: token.outcome.clone(),
+                    best_bid: book
+                        .and_then(|book| book.best_bid())
+                        .map(|level| level.price),
+                    best_ask: book
+                        .and_then(|book| book.best_ask())
+                        .map(|level| level.price),
+                    mid: book.and_then(OrderBookSummary::mid_price),
+                }
+            })
+            .collect();
+
+        Self {
+            condition_id: condition_id.into(),
+            outcomes,
+        }
+    }
+}
+
 /// Negative risk response
 #[derive(Debug, Deserialize)]
 pub struct NegRiskResponse {
@@ -226,6 +459,47 @@ mod tests {
         assert!(!market.ends_within(TimeDelta::hours(1)));
     }
 
+    #[test]
+    fn test_ends_within_with_fixed_clock() {
+        use crate::clock::FixedClock;
+
+        let end_date = DateTime::parse_from_rfc3339("2025-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let market = create_test_market(Some(end_date));
+        let clock = FixedClock::new(end_date - TimeDelta::hours(2));
+
+        assert!(market.ends_within_with_clock(TimeDelta::hours(2), &clock));
+        assert!(!market.ends_within_with_clock(TimeDelta::hours(1), &clock));
+    }
+
+    #[test]
+    fn test_accepting_since() {
+        use crate::clock::FixedClock;
+
+        // Test market started accepting orders at 2024-12-29T22:38:10Z
+        let mut market = create_test_market(None);
+        let clock = FixedClock::new(
+            DateTime::parse_from_rfc3339("2024-12-30T22:38:10Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        assert_eq!(
+            market.accepting_since_with_clock(&clock),
+            Some(TimeDelta::days(1))
+        );
+
+        let before = FixedClock::new(
+            DateTime::parse_from_rfc3339("2024-12-28T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        assert_eq!(market.accepting_since_with_clock(&before), None);
+
+        market.accepting_orders = false;
+        assert_eq!(market.accepting_since_with_clock(&clock), None);
+    }
+
     #[test]
     fn test_ends_within_no_end_date() {
         // Perpetual market (no end date) should return true
@@ -243,4 +517,173 @@ mod tests {
         assert!(market.ends_within(TimeDelta::hours(1)));
         assert!(market.ends_within(TimeDelta::days(7)));
     }
+
+    #[test]
+    fn test_deserialize_reward_earning() {
+        let json = r#"{
+            "date": "2024-11-05",
+            "condition_id": "0xabc",
+            "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+            "maker_address": "0xdef",
+            "earnings": 1.25,
+            "asset_rate": 1
+        }"#;
+
+        let earning: RewardEarning = serde_json::from_str(json).unwrap();
+        assert_eq!(earning.market, "0xabc");
+        assert_eq!(earning.earnings, Decimal::new(125, 2));
+        assert_eq!(earning.date, NaiveDate::from_ymd_opt(2024, 11, 5).unwrap());
+    }
+
+    #[test]
+    fn test_is_tradeable() {
+        let mut market = create_test_market(None);
+        let start = market.accepting_order_timestamp.unwrap();
+
+        assert!(market.is_tradeable());
+        assert!(market.is_tradeable_at(start));
+        assert!(!market.is_tradeable_at(start - TimeDelta::seconds(1)));
+
+        market.accepting_order_timestamp = None;
+        assert!(market.is_tradeable_at(start - TimeDelta::seconds(1)));
+
+        for flag in 0..5 {
+            let mut market = create_test_market(None);
+            match flag {
+                0 => market.active = false,
+                1 => market.closed = true,
+                2 => market.archived = true,
+                3 => market.accepting_orders = false,
+                _ => market.enable_order_book = false,
+            }
+            assert!(!market.is_tradeable());
+            assert!(!market.is_tradeable_at(start));
+        }
+    }
+
+    #[test]
+    fn test_ensure_accepting_orders() {
+        let market = create_test_market(None);
+        assert!(market.ensure_accepting_orders().is_ok());
+    }
+
+    #[test]
+    fn test_ensure_accepting_orders_paused() {
+        let mut market = create_test_market(None);
+        market.accepting_orders = false;
+
+        let result = market.ensure_accepting_orders();
+        assert!(matches!(result, Err(Error::MarketNotAcceptingOrders(id)) if id == "test"));
+    }
+
+    #[test]
+    fn test_ensure_accepting_orders_not_started() {
+        let mut market = create_test_market(None);
+        market.accepting_order_timestamp = Some(Utc::now() + TimeDelta::hours(1));
+
+        assert!(market.ensure_accepting_orders().is_err());
+    }
+
+    #[test]
+    fn test_token_for_outcome() {
+        let market = create_test_market(None);
+        assert_eq!(market.token_for_outcome("no").unwrap().token_id, "token2");
+        assert_eq!(market.token_for_outcome("Yes").unwrap().token_id, "token1");
+        assert!(market.token_for_outcome("Maybe").is_none());
+    }
+
+    #[test]
+    fn test_book_metrics_from_book() {
+        use crate::types::PriceLevel;
+        use rust_decimal_macros::dec;
+
+        let level = |price, size| PriceLevel { price, size };
+        let mut book = OrderBookSummary {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            hash: "0xhash".to_string(),
+            timestamp: 0,
+            bids: vec![level(dec!(0.47), dec!(10)), level(dec!(0.48), dec!(5))],
+            asks: vec![level(dec!(0.53), dec!(8)), level(dec!(0.52), dec!(4))],
+        };
+
+        let metrics = BookMetrics::from_book(&book, dec!(0.01));
+        assert_eq!(metrics.best_bid, Some(dec!(0.48)));
+        assert_eq!(metrics.best_ask, Some(dec!(0.52)));
+        assert_eq!(metrics.midpoint, Some(dec!(0.50)));
+        assert_eq!(metrics.spread, Some(dec!(0.04)));
+
+        book.asks.clear();
+        let metrics = BookMetrics::from_book(&book, dec!(0.01));
+        assert_eq!(metrics.best_bid, Some(dec!(0.48)));
+        assert_eq!(metrics.midpoint, None);
+        assert_eq!(metrics.spread, None);
+    }
+
+    #[test]
+    fn test_rewards_rates() {
+        use rust_decimal_macros::dec;
+
+        let rate = |asset_address: &str, rewards_daily_rate| RewardsRates {
+            asset_address: asset_address.to_string(),
+            rewards_daily_rate,
+        };
+        let rewards = Rewards {
+            rates: Some(vec![rate("0xUSDC", dec!(25)), rate("0xother", dec!(5))]),
+            min_size: dec!(50),
+            max_spread: dec!(3.5),
+        };
+
+        assert_eq!(rewards.daily_rate_for("0xusdc"), Some(dec!(25)));
+        assert_eq!(rewards.daily_rate_for("0xmissing"), None);
+        assert_eq!(rewards.total_daily_rate(), dec!(30));
+        assert!(rewards.is_active());
+
+        let inactive = Rewards {
+            rates: Some(vec![rate("0xUSDC", dec!(0))]),
+            ..rewards.clone()
+        };
+        assert!(!inactive.is_active());
+        assert!(!Rewards {
+            rates: None,
+            ..rewards
+        }
+        .is_active());
+    }
+
+    #[test]
+    fn test_ticker_from_books() {
+        use super::super::order::PriceLevel;
+        use rust_decimal_macros::dec;
+
+        let market = create_test_market(None);
+        let book = OrderBookSummary {
+            market: "test".to_string(),
+            asset_id: "token1".to_string(),
+            hash: "0xhash".to_string(),
+            timestamp: 0,
+            bids: vec![
+                PriceLevel {
+                    price: dec!(0.40),
+                    size: dec!(10),
+                },
+                PriceLevel {
+                    price: dec!(0.42),
+                    size: dec!(5),
+                },
+            ],
+            asks: vec![PriceLevel {
+                price: dec!(0.46),
+                size: dec!(10),
+            }],
+        };
+
+        let ticker = Ticker::from_books("test", &market.tokens, &[book]);
+        assert_eq!(ticker.outcomes.len(), 2);
+        assert_eq!(ticker.outcomes[0].outcome, "Yes");
+        assert_eq!(ticker.outcomes[0].best_bid, Some(dec!(0.42)));
+        assert_eq!(ticker.outcomes[0].mid, Some(dec!(0.44)));
+        assert_eq!(ticker.outcomes[1].best_ask, None);
+        assert_eq!(ticker.outcomes[1].mid, None);
+    }
 }
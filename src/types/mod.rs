@@ -19,3 +19,7 @@ pub use trade::*;
 pub use websocket::*;
 
 // Keep serde_helpers internal but accessible within crate
+pub(crate) use serde_helpers::deserialize_each;
+
+#[cfg(test)]
+pub(crate) use order::open_order_json;
@@ -1,12 +1,20 @@
 use super::enums::{OrderType, Side};
-use crate::error::Result;
-use crate::{orders::calculate_market_price, OrderId};
-use alloy_primitives::U256;
+use crate::error::{Error, Result};
+use crate::orders::{calculate_market_price, is_valid_price};
+use crate::signing::{order_domain, Order};
+use crate::utils::time::to_unix_secs;
+use crate::OrderId;
+use alloy_primitives::{hex, Address, Signature, U256};
+use alloy_sol_types::SolStruct;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
 
+/// Highest fee rate an order can carry (100%)
+const MAX_FEE_RATE_BPS: u32 = 10_000;
+
 /// Arguments for creating a limit order
 #[derive(Debug, Clone)]
 pub struct OrderArgs {
@@ -25,24 +33,109 @@ impl OrderArgs {
             side,
         }
     }
+
+    /// Check the price and size before an order is built
+    ///
+    /// The price must be a multiple of `tick_size` strictly between 0 and 1, as
+    /// checked by [`is_valid_price`], and the size must be positive.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] naming the offending field.
+    pub fn validate(&self, tick_size: Decimal) -> Result<()> {
+        if !is_valid_price(self.price, tick_size) {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid price: {} is not a multiple of tick size {} between 0 and 1",
+                self.price, tick_size
+            )));
+        }
+        if self.size <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid size: {} must be positive",
+                self.size
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Size of a market order, in shares or in collateral (USDC)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Amount {
+    /// Number of outcome shares to buy or sell
+    Shares(Decimal),
+    /// Amount of collateral to spend (buy) or receive (sell)
+    Collateral(Decimal),
+}
+
+impl Amount {
+    /// Get the amount regardless of its unit
+    pub fn value(&self) -> Decimal {
+        match self {
+            Amount::Shares(value) | Amount::Collateral(value) => *value,
+        }
+    }
 }
 
 /// Arguments for creating a market order
 #[derive(Debug, Clone)]
 pub struct MarketOrderArgs {
     pub token_id: String,
-    pub amount: Decimal,
+    pub amount: Amount,
     pub side: Side,
 }
 
 impl MarketOrderArgs {
+    /// Create market order arguments
+    ///
+    /// `amount` is in collateral for a buy and in shares for a sell.
     pub fn new(token_id: impl Into<String>, amount: Decimal, side: Side) -> Self {
+        let amount = match side {
+            Side::Buy => Amount::Collateral(amount),
+            Side::Sell => Amount::Shares(amount),
+        };
+
         Self {
             token_id: token_id.into(),
             amount,
             side,
         }
     }
+
+    /// Create market order arguments for a collateral amount on either side
+    ///
+    /// A buy spends `collateral`; a sell sells as many shares as it takes to
+    /// receive `collateral`. See [`OrderBuilder::market_order_size`](crate::orders::OrderBuilder::market_order_size)
+    /// for how the amount is converted to shares.
+    pub fn new_by_value(token_id: impl Into<String>, collateral: Decimal, side: Side) -> Self {
+        Self {
+            token_id: token_id.into(),
+            amount: Amount::Collateral(collateral),
+            side,
+        }
+    }
+
+    /// Check the amount before an order is built
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] if the amount is not positive.
+    pub fn validate(&self) -> Result<()> {
+        if self.amount.value() <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid amount: {} must be positive",
+                self.amount.value()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Share count of a market order, as computed by [`OrderBuilder::market_order_size`](crate::orders::OrderBuilder::market_order_size)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketOrderSize {
+    /// Shares bought or sold, rounded down to the market's size precision
+    pub shares: Decimal,
+    /// Collateral left over after rounding the order down
+    pub unspent: Decimal,
 }
 
 /// Extra optional arguments for order creation
@@ -82,6 +175,43 @@ impl ExtraOrderArgs {
         self.taker = taker.into();
         self
     }
+
+    /// Check the arguments before an order is built
+    ///
+    /// The taker must be a hex address, with a valid EIP-55 checksum if it is
+    /// mixed-case. The fee rate cannot exceed 10 000 bps and the nonce must fit
+    /// in a `u64`.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] naming the offending field.
+    pub fn validate(&self) -> Result<()> {
+        let taker = parse_address("taker", &self.taker)?;
+        let hex_part = self.taker.trim_start_matches("0x");
+        let mixed_case = hex_part.chars().any(|c| c.is_ascii_uppercase())
+            && hex_part.chars().any(|c| c.is_ascii_lowercase());
+        if mixed_case && taker.to_checksum(None) != self.taker {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid taker: bad checksum for {}",
+                self.taker
+            )));
+        }
+
+        if self.fee_rate_bps > MAX_FEE_RATE_BPS {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid fee_rate_bps: {} exceeds {}",
+                self.fee_rate_bps, MAX_FEE_RATE_BPS
+            )));
+        }
+
+        if self.nonce > U256::from(u64::MAX) {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid nonce: {} is out of range",
+                self.nonce
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 /// Options for creating orders
@@ -89,9 +219,25 @@ impl ExtraOrderArgs {
 pub struct CreateOrderOptions {
     pub tick_size: Option<Decimal>,
     pub neg_risk: Option<bool>,
+    /// Maximum fee rate (in basis points) accepted for the order, if any
+    pub max_fee_rate_bps: Option<u32>,
+    /// Minimum seconds between now and a GTD expiration
+    /// (defaults to [`DEFAULT_GTD_BUFFER_SECS`](Self::DEFAULT_GTD_BUFFER_SECS))
+    pub gtd_buffer_secs: Option<u64>,
+    /// Book levels a FOK market order must be fully fillable against
+    /// (asks for a buy, bids for a sell); unset skips the check
+    pub fok_book: Option<Vec<PriceLevel>>,
+    /// Price the slippage of a market order is measured from
+    pub reference_price: Option<Decimal>,
+    /// Maximum slippage (in basis points) from `reference_price` accepted for a market order
+    pub max_slippage_bps: Option<u32>,
 }
 
 impl CreateOrderOptions {
+    /// Default GTD expiration buffer: the exchange rejects GTD orders expiring
+    /// less than about a minute out because of matching latency
+    pub const DEFAULT_GTD_BUFFER_SECS: u64 = 60;
+
     pub fn new() -> Self {
         Self::default()
     }
@@ -105,6 +251,97 @@ impl CreateOrderOptions {
         self.neg_risk = Some(neg_risk);
         self
     }
+
+    pub fn max_fee_rate_bps(mut self, max_fee_rate_bps: u32) -> Self {
+        self.max_fee_rate_bps = Some(max_fee_rate_bps);
+        self
+    }
+
+    pub fn gtd_buffer_secs(mut self, gtd_buffer_secs: u64) -> Self {
+        self.gtd_buffer_secs = Some(gtd_buffer_secs);
+        self
+    }
+
+    /// Check a market order can be fully filled against these levels before signing
+    ///
+    /// See [`OrderBuilder::create_market_order`](crate::orders::OrderBuilder::create_market_order).
+    pub fn fok_book(mut self, levels: Vec<PriceLevel>) -> Self {
+        self.fok_book = Some(levels);
+        self
+    }
+
+    /// Reject market orders priced more than `max_slippage_bps` worse than `reference_price`
+    ///
+    /// See [`OrderBuilder::create_market_order`](crate::orders::OrderBuilder::create_market_order).
+    pub fn max_slippage(mut self, reference_price: Decimal, max_slippage_bps: u32) -> Self {
+        self.reference_price = Some(reference_price);
+        self.max_slippage_bps = Some(max_slippage_bps);
+        self
+    }
+}
+
+/// Market parameters needed to build orders
+///
+/// Produced by [`GammaMarket::to_order_context`](super::GammaMarket::to_order_context)
+/// to go straight from market discovery to trading.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderContext {
+    pub condition_id: String,
+    /// CLOB token IDs, in the same order as `outcomes`
+    pub token_ids: Vec<String>,
+    pub outcomes: Vec<String>,
+    pub tick_size: Decimal,
+    pub neg_risk: bool,
+}
+
+impl OrderContext {
+    /// Get the token ID for an outcome name (case-insensitive)
+    pub fn token_id_for(&self, outcome: &str) -> Option<&str> {
+        self.outcomes
+            .iter()
+            .position(|o| o.eq_ignore_ascii_case(outcome))
+            .and_then(|i| self.token_ids.get(i))
+            .map(String::as_str)
+    }
+
+    /// Check whether the market has exactly two outcomes
+    pub fn is_binary(&self) -> bool {
+        self.outcomes.len() == 2 && self.token_ids.len() == 2
+    }
+
+    /// Get the token ID of the other outcome in a binary market
+    ///
+    /// The complement of a token only exists for binary markets: on a
+    /// categorical market the other outcomes do not add up to `1 - price`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedMarket`] if the market does not have exactly two
+    /// outcomes, and [`Error::InvalidParameter`] if `token_id` is not one of its tokens.
+    pub fn complement_token_id(&self, token_id: &str) -> Result<&str> {
+        if !self.is_binary() {
+            return Err(Error::UnsupportedMarket(format!(
+                "{} has {} outcomes, complement requires 2",
+                self.condition_id,
+                self.outcomes.len()
+            )));
+        }
+
+        match self.token_ids.iter().position(|id| id == token_id) {
+            Some(i) => Ok(&self.token_ids[1 - i]),
+            None => Err(Error::InvalidParameter(format!(
+                "Token {} is not part of market {}",
+                token_id, self.condition_id
+            ))),
+        }
+    }
+
+    /// Build the [`CreateOrderOptions`] for this market
+    pub fn create_order_options(&self) -> CreateOrderOptions {
+        CreateOrderOptions::new()
+            .tick_size(self.tick_size)
+            .neg_risk(self.neg_risk)
+    }
 }
 
 /// Signed order request ready to be posted
@@ -126,6 +363,77 @@ pub struct SignedOrderRequest {
     pub signature: String,
 }
 
+impl SignedOrderRequest {
+    /// Serialize the order to the JSON used in the API's `order` field
+    ///
+    /// The output can be stored or sent to another process and turned back into an
+    /// order with [`from_json`](Self::from_json), e.g. to sign in one place and post
+    /// from another.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserialize an order produced by [`to_json`](Self::to_json)
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Check that `signature` was produced by `signer` for this order
+    ///
+    /// Recomputes the EIP-712 hash of the order for the given chain and exchange
+    /// contract, recovers the signing address from the signature and compares it
+    /// to `signer`. No network call is made.
+    ///
+    /// # Errors
+    /// Returns an error if a field or the signature cannot be parsed.
+    pub fn verify_signature(&self, chain_id: u64, exchange: Address) -> Result<bool> {
+        let signer = parse_address("signer", &self.signer)?;
+        let hash = self
+            .to_eip712_order()?
+            .eip712_signing_hash(&order_domain(chain_id, exchange));
+
+        let bytes = hex::decode(&self.signature)
+            .map_err(|e| Error::Signing(format!("Invalid signature hex: {}", e)))?;
+        let signature = Signature::try_from(bytes.as_slice())
+            .map_err(|e| Error::Signing(format!("Invalid signature: {}", e)))?;
+        let recovered = signature
+            .recover_address_from_prehash(&hash)
+            .map_err(|e| Error::Signing(format!("Failed to recover signer: {}", e)))?;
+
+        Ok(recovered == signer)
+    }
+
+    /// Rebuild the EIP-712 order struct that was signed
+    fn to_eip712_order(&self) -> Result<Order> {
+        let side: Side = self.side.parse()?;
+        Ok(Order {
+            salt: U256::from(self.salt),
+            maker: parse_address("maker", &self.maker)?,
+            signer: parse_address("signer", &self.signer)?,
+            taker: parse_address("taker", &self.taker)?,
+            tokenId: parse_u256("tokenId", &self.token_id)?,
+            makerAmount: parse_u256("makerAmount", &self.maker_amount)?,
+            takerAmount: parse_u256("takerAmount", &self.taker_amount)?,
+            expiration: parse_u256("expiration", &self.expiration)?,
+            nonce: parse_u256("nonce", &self.nonce)?,
+            feeRateBps: parse_u256("feeRateBps", &self.fee_rate_bps)?,
+            side: side.to_u8(),
+            signatureType: self.signature_type,
+        })
+    }
+}
+
+fn parse_address(field: &str, value: &str) -> Result<Address> {
+    value
+        .parse()
+        .map_err(|e| Error::InvalidParameter(format!("Invalid {}: {}", field, e)))
+}
+
+fn parse_u256(field: &str, value: &str) -> Result<U256> {
+    U256::from_str_radix(value, 10)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid {}: {}", field, e)))
+}
+
 /// Order to be posted to the API
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -143,6 +451,21 @@ impl PostOrder {
             order_type,
         }
     }
+
+    /// Get the signed order being posted
+    pub fn order(&self) -> &SignedOrderRequest {
+        &self.order
+    }
+
+    /// Get the owner (API key) posting the order
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// Get the order type
+    pub fn order_type(&self) -> OrderType {
+        self.order_type
+    }
 }
 
 /// Response for open orders query
@@ -179,6 +502,47 @@ pub struct OpenOrder {
     pub created_at: u64,
 }
 
+/// Result of reconciling expected open orders against the server
+#[derive(Debug, Default)]
+pub struct Reconciliation {
+    /// Expected orders that are still open
+    pub still_open: Vec<OpenOrder>,
+    /// Expected orders that are no longer open (filled, cancelled or expired)
+    pub filled_or_gone: Vec<OrderId>,
+    /// Open orders that were not expected
+    pub unexpected: Vec<OpenOrder>,
+}
+
+impl Reconciliation {
+    /// Diff the expected order IDs against the orders currently open
+    pub fn new(expected: &[OrderId], open_orders: Vec<OpenOrder>) -> Self {
+        let expected_ids: HashSet<&OrderId> = expected.iter().collect();
+        let open_ids: HashSet<OrderId> = open_orders.iter().map(|o| o.id.clone()).collect();
+
+        let (still_open, unexpected) = open_orders
+            .into_iter()
+            .partition(|order| expected_ids.contains(&order.id));
+
+        let mut seen = HashSet::new();
+        let filled_or_gone = expected
+            .iter()
+            .filter(|id| !open_ids.contains(*id) && seen.insert(*id))
+            .cloned()
+            .collect();
+
+        Self {
+            still_open,
+            filled_or_gone,
+            unexpected,
+        }
+    }
+
+    /// Check whether the server state matches the expected orders exactly
+    pub fn is_consistent(&self) -> bool {
+        self.filled_or_gone.is_empty() && self.unexpected.is_empty()
+    }
+}
+
 /// Parameters for querying open orders
 #[derive(Debug, Clone, Default)]
 pub struct OpenOrderParams {
@@ -265,6 +629,33 @@ impl OrderBookSummary {
         self.bids.is_empty() && self.asks.is_empty()
     }
 
+    /// Highest priced bid level
+    ///
+    /// Levels are scanned rather than assuming the API's ordering.
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        self.bids.iter().max_by_key(|level| level.price)
+    }
+
+    /// Lowest priced ask level
+    pub fn best_ask(&self) -> Option<&PriceLevel> {
+        self.asks.iter().min_by_key(|level| level.price)
+    }
+
+    /// Midpoint of the best bid and best ask, `None` if either side is empty
+    pub fn mid_price(&self) -> Option<Decimal> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / Decimal::TWO)
+    }
+
+    /// Best ask minus best bid, `None` if either side is empty
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Time of the snapshot in Unix seconds (`timestamp` is in milliseconds)
+    pub fn timestamp_secs(&self) -> u64 {
+        to_unix_secs(self.timestamp)
+    }
+
     pub fn sort_bids(&self) -> Vec<PriceLevel> {
         let mut bids = self.bids.clone();
         bids.sort_by(|a, b| b.price.cmp(&a.price));
@@ -276,6 +667,130 @@ impl OrderBookSummary {
         asks.sort_by(|a, b| a.price.cmp(&b.price));
         asks
     }
+
+    /// Merge duplicate price levels and sort both sides
+    ///
+    /// Levels sharing a price on the same side are combined by summing their sizes
+    /// and empty levels are dropped. Bids end up sorted best (highest) first and
+    /// asks best (lowest) first. Call this on books from merged or malformed feeds
+    /// before inspecting individual levels.
+    pub fn normalize(&mut self) {
+        let to_levels = |levels: BTreeMap<Decimal, Decimal>| {
+            levels
+                .into_iter()
+                .map(|(price, size)| PriceLevel { price, size })
+        };
+        self.bids = to_levels(merge_levels(&self.bids)).rev().collect();
+        self.asks = to_levels(merge_levels(&self.asks)).collect();
+    }
+
+    /// Total resting size at or better than `price` on the given side
+    ///
+    /// For a resting BUY this sums bids priced at or above `price`; for a SELL it
+    /// sums asks priced at or below `price`. Combined with your own order size this
+    /// gives a rough estimate of queue position at your level.
+    pub fn size_ahead(&self, side: Side, price: Decimal) -> Decimal {
+        match side {
+            Side::Buy => self
+                .bids
+                .iter()
+                .filter(|level| level.price >= price)
+                .map(|level| level.size)
+                .sum(),
+            Side::Sell => self
+                .asks
+                .iter()
+                .filter(|level| level.price <= price)
+                .map(|level| level.size)
+                .sum(),
+        }
+    }
+
+    /// Size-weighted mid price (micro-price) of the top of book
+    ///
+    /// Computed as `(bid_size * best_ask + ask_size * best_bid) / (bid_size + ask_size)`,
+    /// which leans towards the side with less resting size. Returns `None` if either
+    /// side is empty or both top levels have zero size.
+    pub fn micro_price(&self) -> Option<Decimal> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+
+        let total_size = bid.size + ask.size;
+        if total_size.is_zero() {
+            return None;
+        }
+
+        Some((bid.size * ask.price + ask.size * bid.price) / total_size)
+    }
+
+    /// Bid/ask spread in basis points of the midpoint
+    ///
+    /// Computed as `(best_ask - best_bid) / midpoint * 10000`. Returns `None` if
+    /// either side is empty or the midpoint is zero.
+    pub fn spread_bps(&self) -> Option<Decimal> {
+        let mid = self.mid_price()?;
+        if mid.is_zero() {
+            return None;
+        }
+
+        Some(self.spread()? / mid * Decimal::from(10_000))
+    }
+
+    /// Mid price from the volume-weighted prices of the first `depth_usdc` of notional on each side
+    ///
+    /// Each side is walked from its best level until `depth_usdc` of notional
+    /// (`price * size`) is covered, and the result is the average of the two
+    /// volume-weighted prices. Tiny orders at the top of book barely move it,
+    /// which makes it a steadier fair value than the midpoint or [`micro_price`](Self::micro_price).
+    /// A side with less than `depth_usdc` resting uses all of its liquidity.
+    /// Returns `None` if `depth_usdc` is not positive or either side has no size.
+    pub fn weighted_mid(&self, depth_usdc: Decimal) -> Option<Decimal> {
+        if depth_usdc <= Decimal::ZERO {
+            return None;
+        }
+
+        let bid = vwap_within_notional(&self.sort_bids(), depth_usdc)?;
+        let ask = vwap_within_notional(&self.sort_asks(), depth_usdc)?;
+        Some((bid + ask) / Decimal::TWO)
+    }
+}
+
+/// Volume-weighted price of sorted levels, up to `depth` of notional
+fn vwap_within_notional(levels: &[PriceLevel], depth: Decimal) -> Option<Decimal> {
+    let mut notional = Decimal::ZERO;
+    let mut shares = Decimal::ZERO;
+
+    for level in levels {
+        let remaining = depth - notional;
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+
+        let level_notional = level.price * level.size;
+        if level_notional <= remaining {
+            notional += level_notional;
+            shares += level.size;
+        } else {
+            shares += remaining / level.price;
+            notional = depth;
+        }
+    }
+
+    if shares.is_zero() {
+        return None;
+    }
+    Some(notional / shares)
+}
+
+/// Collect levels into a map keyed by price, summing duplicate prices and
+/// dropping empty levels
+pub(crate) fn merge_levels(levels: &[PriceLevel]) -> BTreeMap<Decimal, Decimal> {
+    let mut merged: BTreeMap<Decimal, Decimal> = BTreeMap::new();
+    for level in levels {
+        *merged.entry(level.price).or_default() += level.size;
+    }
+    merged.retain(|_, size| !size.is_zero());
+    merged
 }
 
 /// Parameters for querying order book
@@ -305,6 +820,19 @@ pub struct PostOrderResponse {
     pub success: bool,
 }
 
+impl PostOrderResponse {
+    /// Typed rejection reason, if the exchange did not accept the order
+    ///
+    /// Useful with batch posts, where each order gets its own response.
+    /// See [`Error::from_order_rejection`] for how messages are classified.
+    pub fn rejection(&self) -> Option<Error> {
+        if self.success {
+            return None;
+        }
+        Some(Error::from_order_rejection(self.error_msg.clone()))
+    }
+}
+
 /// Arguments for posting multiple orders
 #[derive(Debug, Clone)]
 pub struct PostOrderArgs {
@@ -328,5 +856,395 @@ impl PostOrderArgs {
 #[derive(Debug, Deserialize)]
 pub struct CancelOrdersResponse {
     pub canceled: Vec<OrderId>,
-    pub not_canceled: serde_json::Value,
+    /// Orders that could not be canceled, with the reason reported by the server
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_null_default"
+    )]
+    pub not_canceled: HashMap<OrderId, CancelFailureReason>,
+}
+
+/// Reason an order could not be canceled
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum CancelFailureReason {
+    /// The order was already fully matched
+    AlreadyFilled,
+    /// The order was already canceled
+    AlreadyCanceled,
+    /// The order does not exist
+    NotFound,
+    /// Any other reason, with the server's message
+    Other(String),
+}
+
+impl CancelFailureReason {
+    /// Whether the order is gone for good, so retrying the cancel is pointless
+    ///
+    /// Unrecognized reasons are treated as transient.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, CancelFailureReason::Other(_))
+    }
+}
+
+impl From<String> for CancelFailureReason {
+    fn from(message: String) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("not found")
+            || lower.contains("can't be found")
+            || lower.contains("does not exist")
+        {
+            CancelFailureReason::NotFound
+        } else if lower.contains("matched") || lower.contains("filled") {
+            CancelFailureReason::AlreadyFilled
+        } else if lower.contains("canceled") || lower.contains("cancelled") {
+            CancelFailureReason::AlreadyCanceled
+        } else {
+            CancelFailureReason::Other(message)
+        }
+    }
+}
+
+/// JSON for a live 10-share BUY at 0.50 with the given id, as the CLOB returns it
+///
+/// Tests override individual fields before deserializing it into an [`OpenOrder`].
+#[cfg(test)]
+pub(crate) fn open_order_json(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "associate_trades": [],
+        "status": "LIVE",
+        "market": "0xmarket",
+        "original_size": "10",
+        "outcome": "Yes",
+        "maker_address": "0xmaker",
+        "owner": "owner",
+        "price": "0.5",
+        "side": "BUY",
+        "size_matched": "0",
+        "asset_id": "123",
+        "expiration": "0",
+        "order_type": "GTC",
+        "created_at": "1700000000"
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    fn book() -> OrderBookSummary {
+        OrderBookSummary {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            hash: "0xhash".to_string(),
+            timestamp: 0,
+            bids: vec![
+                level(dec!(0.47), dec!(30)),
+                level(dec!(0.49), dec!(20)),
+                level(dec!(0.48), dec!(10)),
+            ],
+            asks: vec![
+                level(dec!(0.52), dec!(15)),
+                level(dec!(0.51), dec!(25)),
+                level(dec!(0.53), dec!(35)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_weighted_mid() {
+        // Bids: 20 @ 0.49 (9.8) then 0.2 / 0.48 shares; asks: 25 @ 0.51 covers 10 USDC
+        let mid = book().weighted_mid(dec!(10)).unwrap();
+        let bid = dec!(10) / (dec!(20) + dec!(0.2) / dec!(0.48));
+        let ask = dec!(0.51);
+        assert_eq!(mid, (bid + ask) / dec!(2));
+
+        // Beyond the available liquidity every level is used
+        let all_bids =
+            (dec!(0.49) * dec!(20) + dec!(0.48) * dec!(10) + dec!(0.47) * dec!(30)) / dec!(60);
+        let all_asks =
+            (dec!(0.51) * dec!(25) + dec!(0.52) * dec!(15) + dec!(0.53) * dec!(35)) / dec!(75);
+        assert_eq!(
+            book().weighted_mid(dec!(1000)).unwrap(),
+            (all_bids + all_asks) / dec!(2)
+        );
+
+        assert_eq!(book().weighted_mid(dec!(0)), None);
+    }
+
+    #[test]
+    fn test_top_of_book() {
+        let book = book();
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.49));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.51));
+        assert_eq!(book.mid_price(), Some(dec!(0.50)));
+        assert_eq!(book.spread(), Some(dec!(0.02)));
+
+        let mut book = book;
+        book.asks.clear();
+        assert!(book.best_ask().is_none());
+        assert_eq!(book.mid_price(), None);
+        assert_eq!(book.spread(), None);
+    }
+
+    #[test]
+    fn test_micro_price() {
+        // Best bid 0.49 x 20, best ask 0.51 x 25: (20 * 0.51 + 25 * 0.49) / 45
+        let micro = book().micro_price().unwrap();
+        assert_eq!(micro.round_dp(6), dec!(0.498889));
+    }
+
+    #[test]
+    fn test_micro_price_one_sided() {
+        let mut book = book();
+        book.asks.clear();
+        assert_eq!(book.micro_price(), None);
+    }
+
+    #[test]
+    fn test_spread_bps() {
+        // Best bid 0.49, best ask 0.51: 0.02 / 0.50 * 10000
+        assert_eq!(book().spread_bps(), Some(dec!(400)));
+
+        let mut book = book();
+        book.bids.clear();
+        assert_eq!(book.spread_bps(), None);
+    }
+
+    #[test]
+    fn test_timestamp_secs() {
+        let mut book = book();
+        book.timestamp = 1_700_000_000_999;
+        assert_eq!(book.timestamp_secs(), 1_700_000_000);
+    }
+
+    #[test]
+    fn test_normalize_merges_duplicate_levels() {
+        let mut book = book();
+        book.bids.push(level(dec!(0.49), dec!(5)));
+        book.bids.push(level(dec!(0.46), dec!(0)));
+        book.asks.push(level(dec!(0.51), dec!(10)));
+        book.normalize();
+
+        let bids: Vec<(Decimal, Decimal)> = book.bids.iter().map(|l| (l.price, l.size)).collect();
+        let asks: Vec<(Decimal, Decimal)> = book.asks.iter().map(|l| (l.price, l.size)).collect();
+        assert_eq!(
+            bids,
+            vec![
+                (dec!(0.49), dec!(25)),
+                (dec!(0.48), dec!(10)),
+                (dec!(0.47), dec!(30))
+            ]
+        );
+        assert_eq!(
+            asks,
+            vec![
+                (dec!(0.51), dec!(35)),
+                (dec!(0.52), dec!(15)),
+                (dec!(0.53), dec!(35))
+            ]
+        );
+        assert_eq!(
+            book.calculate_market_price(Side::Buy, dec!(35)).unwrap(),
+            dec!(0.51)
+        );
+    }
+
+    #[test]
+    fn test_size_ahead_buy() {
+        let book = book();
+        assert_eq!(book.size_ahead(Side::Buy, dec!(0.48)), dec!(30));
+        assert_eq!(book.size_ahead(Side::Buy, dec!(0.50)), dec!(0));
+    }
+
+    #[test]
+    fn test_size_ahead_sell() {
+        let book = book();
+        assert_eq!(book.size_ahead(Side::Sell, dec!(0.52)), dec!(40));
+        assert_eq!(book.size_ahead(Side::Sell, dec!(0.60)), dec!(75));
+    }
+
+    fn signed_order() -> SignedOrderRequest {
+        SignedOrderRequest {
+            salt: 12345,
+            maker: "0xmaker".to_string(),
+            signer: "0xsigner".to_string(),
+            taker: ZERO_ADDRESS.to_string(),
+            token_id: "1234".to_string(),
+            maker_amount: "5500000".to_string(),
+            taker_amount: "10000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side: "BUY".to_string(),
+            signature_type: 0,
+            signature: "0xsig".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_signed_order_json_round_trip() {
+        let order = signed_order();
+        let json = order.to_json().unwrap();
+        let restored = SignedOrderRequest::from_json(&json).unwrap();
+        assert_eq!(restored.to_json().unwrap(), json);
+        assert_eq!(restored.maker_amount, "5500000");
+    }
+
+    #[test]
+    fn test_signed_order_api_field_names() {
+        let value = serde_json::to_value(signed_order()).unwrap();
+        let mut keys: Vec<&str> = value
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            vec![
+                "expiration",
+                "feeRateBps",
+                "maker",
+                "makerAmount",
+                "nonce",
+                "salt",
+                "side",
+                "signature",
+                "signatureType",
+                "signer",
+                "taker",
+                "takerAmount",
+                "tokenId",
+            ]
+        );
+        assert!(value["salt"].is_u64());
+
+        let post = PostOrder::new(signed_order(), "api-key".to_string(), OrderType::Gtc);
+        let value = serde_json::to_value(&post).unwrap();
+        assert_eq!(value["owner"], "api-key");
+        assert_eq!(value["orderType"], "GTC");
+        assert_eq!(value["order"]["tokenId"], "1234");
+    }
+
+    #[test]
+    fn test_cancel_response_not_canceled() {
+        let response: CancelOrdersResponse = serde_json::from_value(serde_json::json!({
+            "canceled": ["a"],
+            "not_canceled": {
+                "b": "order already matched",
+                "c": "order already canceled",
+                "d": "order not found",
+                "e": "rate limited"
+            }
+        }))
+        .unwrap();
+
+        let reason = |id: &str| response.not_canceled[&OrderId::new(id)].clone();
+        assert_eq!(reason("b"), CancelFailureReason::AlreadyFilled);
+        assert_eq!(reason("c"), CancelFailureReason::AlreadyCanceled);
+        assert_eq!(reason("d"), CancelFailureReason::NotFound);
+        assert_eq!(
+            reason("e"),
+            CancelFailureReason::Other("rate limited".to_string())
+        );
+        assert!(!reason("e").is_final());
+
+        let response: CancelOrdersResponse =
+            serde_json::from_value(serde_json::json!({ "canceled": [], "not_canceled": null }))
+                .unwrap();
+        assert!(response.not_canceled.is_empty());
+    }
+
+    fn open_order(id: &str) -> OpenOrder {
+        serde_json::from_value(open_order_json(id)).unwrap()
+    }
+
+    #[test]
+    fn test_reconciliation() {
+        let expected = vec![OrderId::new("a"), OrderId::new("b"), OrderId::new("c")];
+        let open = vec![open_order("a"), open_order("x"), open_order("c")];
+
+        let reconciliation = Reconciliation::new(&expected, open);
+        let still_open: Vec<&str> = reconciliation
+            .still_open
+            .iter()
+            .map(|o| o.id.as_str())
+            .collect();
+        assert_eq!(still_open, vec!["a", "c"]);
+        assert_eq!(reconciliation.filled_or_gone, vec![OrderId::new("b")]);
+        assert_eq!(reconciliation.unexpected.len(), 1);
+        assert_eq!(reconciliation.unexpected[0].id.as_str(), "x");
+        assert!(!reconciliation.is_consistent());
+    }
+
+    #[test]
+    fn test_reconciliation_consistent() {
+        let expected = vec![OrderId::new("a")];
+        let reconciliation = Reconciliation::new(&expected, vec![open_order("a")]);
+        assert!(reconciliation.is_consistent());
+    }
+
+    #[test]
+    fn test_extra_order_args_validate() {
+        assert!(ExtraOrderArgs::new().validate().is_ok());
+        assert!(ExtraOrderArgs::new()
+            .taker("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+            .validate()
+            .is_ok());
+
+        let invalid = [
+            ExtraOrderArgs::new().taker("not an address"),
+            // Mixed case with a wrong checksum
+            ExtraOrderArgs::new().taker("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            ExtraOrderArgs::new().fee_rate_bps(10_001),
+            ExtraOrderArgs::new().nonce(U256::from(u64::MAX) + U256::from(1)),
+        ];
+        for extras in invalid {
+            assert!(matches!(extras.validate(), Err(Error::InvalidParameter(_))));
+        }
+    }
+
+    #[test]
+    fn test_order_args_validate() {
+        let tick = dec!(0.01);
+        assert!(OrderArgs::new("1", dec!(0.55), dec!(10), Side::Buy)
+            .validate(tick)
+            .is_ok());
+
+        let invalid = [
+            OrderArgs::new("1", dec!(0), dec!(10), Side::Buy),
+            OrderArgs::new("1", dec!(1), dec!(10), Side::Buy),
+            OrderArgs::new("1", dec!(0.555), dec!(10), Side::Buy),
+            OrderArgs::new("1", dec!(0.55), dec!(0), Side::Sell),
+            OrderArgs::new("1", dec!(0.55), dec!(-1), Side::Sell),
+        ];
+        for args in invalid {
+            assert!(matches!(
+                args.validate(tick),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_market_order_args_validate() {
+        assert!(MarketOrderArgs::new("1", dec!(25), Side::Buy)
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            MarketOrderArgs::new("1", dec!(0), Side::Sell).validate(),
+            Err(Error::InvalidParameter(msg)) if msg.contains("amount")
+        ));
+        assert!(MarketOrderArgs::new_by_value("1", dec!(-5), Side::Sell)
+            .validate()
+            .is_err());
+    }
 }
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer};
 use std::fmt::Display;
 use std::str::FromStr;
@@ -25,6 +26,35 @@ where
     }
 }
 
+/// Deserialize every element of a JSON array independently
+///
+/// Returns the elements that parsed, plus the index, raw value and error of each
+/// element that did not.
+pub(crate) fn deserialize_each<T: DeserializeOwned>(
+    values: Vec<serde_json::Value>,
+) -> (Vec<T>, Vec<(usize, serde_json::Value, crate::Error)>) {
+    let mut parsed = Vec::with_capacity(values.len());
+    let mut failed = Vec::new();
+
+    for (index, value) in values.into_iter().enumerate() {
+        match T::deserialize(&value) {
+            Ok(item) => parsed.push(item),
+            Err(e) => failed.push((index, value, crate::Error::Json(e))),
+        }
+    }
+
+    (parsed, failed)
+}
+
+/// Deserialize a value, treating `null` as its default
+pub fn deserialize_null_default<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 /// Deserialize Decimal from JSON number (f64/int) or string
 pub fn deserialize_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
 where
@@ -40,7 +70,7 @@ where
     }
 
     match Repr::deserialize(deserializer)? {
-        Repr::Str(s) => Decimal::from_str(&s).map_err(serde::de::Error::custom),
+        Repr::Str(s) => parse_decimal_str(&s).map_err(serde::de::Error::custom),
         Repr::F64(f) => {
             Decimal::from_f64(f).ok_or_else(|| serde::de::Error::custom("invalid f64 for Decimal"))
         }
@@ -49,6 +79,16 @@ where
     }
 }
 
+/// Parse a decimal string, accepting plain (`-1.5`) and scientific (`-1.5e-3`) notation
+fn parse_decimal_str(s: &str) -> Result<Decimal, rust_decimal::Error> {
+    let s = s.trim();
+    if s.contains(['e', 'E']) {
+        Decimal::from_scientific(s)
+    } else {
+        Decimal::from_str(s)
+    }
+}
+
 /// Deserialize Option<DateTime<Utc>> from an optional datetime string
 /// Supports multiple formats:
 /// - RFC3339: "2022-07-27T14:41:12.085+00:00" or "2022-07-27T14:41:12.085Z"
@@ -116,6 +156,55 @@ mod tests {
     use super::*;
     use serde::Deserialize;
 
+    #[derive(Deserialize)]
+    struct DecimalStruct {
+        #[serde(deserialize_with = "deserialize_decimal")]
+        value: Decimal,
+    }
+
+    fn parse_decimal(json: &str) -> Decimal {
+        serde_json::from_str::<DecimalStruct>(json).unwrap().value
+    }
+
+    #[test]
+    fn test_deserialize_decimal_negative() {
+        assert_eq!(parse_decimal(r#"{"value": "-1.5"}"#), Decimal::new(-15, 1));
+        assert_eq!(parse_decimal(r#"{"value": -1.5}"#), Decimal::new(-15, 1));
+        assert_eq!(parse_decimal(r#"{"value": -3}"#), Decimal::new(-3, 0));
+    }
+
+    #[test]
+    fn test_deserialize_decimal_scientific() {
+        assert_eq!(
+            parse_decimal(r#"{"value": "-1.5e-3"}"#),
+            Decimal::new(-15, 4)
+        );
+        assert_eq!(parse_decimal(r#"{"value": "2E2"}"#), Decimal::new(200, 0));
+        assert_eq!(parse_decimal(r#"{"value": -1.5e-3}"#), Decimal::new(-15, 4));
+    }
+
+    #[test]
+    fn test_deserialize_decimal_invalid() {
+        let result = serde_json::from_str::<DecimalStruct>(r#"{"value": "abc"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_each() {
+        let values = vec![
+            serde_json::json!({ "value": "1.5" }),
+            serde_json::json!({ "value": "abc" }),
+            serde_json::json!({ "value": 2 }),
+        ];
+
+        let (parsed, failed) = deserialize_each::<DecimalStruct>(values);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].value, Decimal::new(2, 0));
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, 1);
+        assert_eq!(failed[0].1["value"], "abc");
+    }
+
     #[derive(Deserialize)]
     struct TestStruct {
         #[serde(deserialize_with = "deserialize_optional_datetime")]
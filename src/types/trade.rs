@@ -1,7 +1,11 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 
-use crate::{types::ActivityType, Side};
+use crate::{
+    types::{ActivityType, MarketOrderArgs},
+    Side,
+};
 
 /// Custom deserializer for optional Side that treats empty strings as None
 fn deserialize_optional_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
@@ -91,6 +95,84 @@ pub struct Position {
     pub negative_risk: bool,
 }
 
+impl Position {
+    /// Build the market order that closes this position
+    ///
+    /// Closing a position sells all `size` shares of `asset` at market.
+    pub fn close_order_args(&self) -> MarketOrderArgs {
+        MarketOrderArgs::new(self.asset.clone(), self.size, Side::Sell)
+    }
+
+    /// Price at which closing the remaining shares makes the position flat overall
+    ///
+    /// Profit already realized from partial sells lowers the break-even below
+    /// `avg_price`, losses raise it: `avg_price - realized_pnl / size`. Returns
+    /// `avg_price` if no shares are left.
+    pub fn break_even_price(&self) -> Decimal {
+        if self.size.is_zero() {
+            return self.avg_price;
+        }
+        self.avg_price - self.realized_pnl / self.size
+    }
+
+    /// Unrealized PnL of the remaining shares if the outcome traded at `price`
+    pub fn unrealized_pnl_at(&self, price: Decimal) -> Decimal {
+        (price - self.avg_price) * self.size
+    }
+}
+
+/// Net exposure of a set of positions within a single event
+///
+/// Returned by [`net_event_exposure`]. Shares in outcome index 0 (usually "Yes")
+/// count as long, every other outcome counts as short.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventExposure {
+    /// Total shares held in the first outcome of each market
+    pub long_shares: Decimal,
+    /// Total shares held in the other outcomes of each market
+    pub short_shares: Decimal,
+    /// Current USDC value of all positions in the event
+    pub net_value: Decimal,
+    /// Maximum loss of the cost basis over all resolutions
+    ///
+    /// Computed as the total `initial_value` minus the payout guaranteed by
+    /// holding both outcomes of a market (the smaller side always redeems for
+    /// 1 USDC per share), floored at zero.
+    pub worst_case_loss: Decimal,
+}
+
+/// Compute the net exposure of the positions belonging to an event
+///
+/// Positions with a different `event_id` are ignored.
+///
+/// # Arguments
+/// * `positions` - Positions to aggregate, typically from [`DataClient::get_positions`](crate::client::DataClient::get_positions)
+/// * `event_id` - The event to report on
+pub fn net_event_exposure(positions: &[Position], event_id: &str) -> EventExposure {
+    let mut exposure = EventExposure::default();
+    let mut cost_basis = Decimal::ZERO;
+    // Long and short shares per market, to find fully hedged sets
+    let mut markets: HashMap<&str, (Decimal, Decimal)> = HashMap::new();
+
+    for position in positions.iter().filter(|p| p.event_id == event_id) {
+        let market = markets.entry(position.condition_id.as_str()).or_default();
+        if position.outcome_index == 0 {
+            exposure.long_shares += position.size;
+            market.0 += position.size;
+        } else {
+            exposure.short_shares += position.size;
+            market.1 += position.size;
+        }
+        exposure.net_value += position.current_value;
+        cost_basis += position.initial_value;
+    }
+
+    let guaranteed_payout: Decimal = markets.values().map(|(long, short)| *long.min(short)).sum();
+    exposure.worst_case_loss = (cost_basis - guaranteed_payout).max(Decimal::ZERO);
+
+    exposure
+}
+
 /// User position value summary
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct PositionValue {
@@ -221,6 +303,8 @@ pub struct TradeParams {
     pub asset_id: Option<String>,
     pub before: Option<u64>,
     pub after: Option<u64>,
+    /// Pagination cursor of the page to fetch
+    pub cursor: Option<String>,
 }
 
 impl TradeParams {
@@ -258,8 +342,13 @@ impl TradeParams {
         self
     }
 
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, String)> {
-        let mut params = Vec::with_capacity(6);
+        let mut params = Vec::with_capacity(7);
 
         if let Some(ref id) = self.id {
             params.push(("id", id.clone()));
@@ -285,6 +374,94 @@ impl TradeParams {
             params.push(("maker_address", maker_address.clone()));
         }
 
+        if let Some(ref cursor) = self.cursor {
+            params.push(("next_cursor", cursor.clone()));
+        }
+
         params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_close_order_args() {
+        let position = Position {
+            asset: "123".to_string(),
+            size: dec!(42.5),
+            ..Default::default()
+        };
+
+        let args = position.close_order_args();
+        assert_eq!(args.token_id, "123");
+        assert_eq!(args.amount, crate::types::Amount::Shares(dec!(42.5)));
+        assert_eq!(args.side, Side::Sell);
+    }
+
+    #[test]
+    fn test_break_even_price() {
+        let mut position = Position {
+            size: dec!(100),
+            avg_price: dec!(0.40),
+            realized_pnl: dec!(5),
+            ..Default::default()
+        };
+        assert_eq!(position.break_even_price(), dec!(0.35));
+        assert_eq!(position.unrealized_pnl_at(dec!(0.35)), dec!(-5));
+        assert_eq!(position.unrealized_pnl_at(dec!(0.50)), dec!(10));
+
+        position.realized_pnl = dec!(-2);
+        assert_eq!(position.break_even_price(), dec!(0.42));
+
+        position.size = Decimal::ZERO;
+        assert_eq!(position.break_even_price(), dec!(0.40));
+    }
+
+    fn position(
+        event_id: &str,
+        condition_id: &str,
+        outcome_index: u32,
+        size: Decimal,
+        initial_value: Decimal,
+        current_value: Decimal,
+    ) -> Position {
+        Position {
+            event_id: event_id.to_string(),
+            condition_id: condition_id.to_string(),
+            outcome_index,
+            size,
+            initial_value,
+            current_value,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_net_event_exposure() {
+        let positions = vec![
+            position("e1", "m1", 0, dec!(100), dec!(40), dec!(50)),
+            position("e1", "m1", 1, dec!(30), dec!(15), dec!(15)),
+            position("e1", "m2", 0, dec!(10), dec!(2), dec!(1)),
+            position("e2", "m3", 0, dec!(500), dec!(250), dec!(250)),
+        ];
+
+        let exposure = net_event_exposure(&positions, "e1");
+        assert_eq!(exposure.long_shares, dec!(110));
+        assert_eq!(exposure.short_shares, dec!(30));
+        assert_eq!(exposure.net_value, dec!(66));
+        // Cost basis 57, of which 30 is locked in by the hedged m1 shares
+        assert_eq!(exposure.worst_case_loss, dec!(27));
+    }
+
+    #[test]
+    fn test_net_event_exposure_unknown_event() {
+        let positions = vec![position("e1", "m1", 0, dec!(100), dec!(40), dec!(50))];
+        assert_eq!(
+            net_event_exposure(&positions, "e9"),
+            EventExposure::default()
+        );
+    }
+}
@@ -1,8 +1,12 @@
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use super::order::PriceLevel;
 use super::Side;
+use crate::error::Result;
+use crate::utils::time::parse_ws_timestamp;
 
 // ============================================================================
 // Market WebSocket Events
@@ -29,6 +33,25 @@ pub enum WsEvent {
     TickSizeChange(TickSizeChangeEvent),
 }
 
+impl WsEvent {
+    /// Time the server emitted the event
+    ///
+    /// Returns `None` for a price change event without a timestamp.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::error::Error::InvalidParameter) if
+    /// the timestamp is not a valid Unix time in milliseconds.
+    pub fn timestamp(&self) -> Result<Option<DateTime<Utc>>> {
+        let timestamp = match self {
+            WsEvent::Book(event) => Some(event.timestamp.as_str()),
+            WsEvent::PriceChange(event) => event.timestamp.as_deref(),
+            WsEvent::LastTradePrice(event) => Some(event.timestamp.as_str()),
+            WsEvent::TickSizeChange(event) => Some(event.timestamp.as_str()),
+        };
+        timestamp.map(parse_ws_timestamp).transpose()
+    }
+}
+
 /// Full order book snapshot event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookEvent {
@@ -49,6 +72,75 @@ pub struct BookEvent {
     pub last_trade_price: Option<String>,
 }
 
+impl BookEvent {
+    /// Check the snapshot's `hash` against its contents
+    ///
+    /// Recomputes the hash with [`compute_book_hash`] and compares it to `hash`,
+    /// ignoring case and a `0x` prefix.
+    ///
+    /// The result is advisory: the hashing scheme is best-effort (see
+    /// [`compute_book_hash`]), so `false` means the snapshot *may* have been
+    /// altered, not that it was. Log or count mismatches rather than dropping
+    /// snapshots or resubscribing on each one.
+    pub fn verify_hash(&self) -> bool {
+        let expected = compute_book_hash(
+            &self.market,
+            &self.asset_id,
+            &self.timestamp,
+            &self.bids,
+            &self.asks,
+        );
+        let hash = self.hash.strip_prefix("0x").unwrap_or(&self.hash);
+        hash.eq_ignore_ascii_case(&expected)
+    }
+}
+
+/// Book summary in the field order the server hashes it
+#[derive(Serialize)]
+struct HashedBook<'a> {
+    market: &'a str,
+    asset_id: &'a str,
+    timestamp: &'a str,
+    bids: &'a [PriceLevel],
+    asks: &'a [PriceLevel],
+    hash: &'a str,
+}
+
+/// Compute the hash of an order book snapshot, as a lowercase hex SHA-1 digest
+///
+/// Follows the scheme of the reference Python client: the book is serialized
+/// to compact JSON (no whitespace) with the fields in the order `market`,
+/// `asset_id`, `timestamp`, `bids`, `asks` and an empty `hash`, and hashed
+/// with SHA-1. Levels are `{"price": "...", "size": "..."}` objects kept in the
+/// order the server sent them, and prices and sizes are the decimal strings as
+/// received, so levels must not be sorted, merged or rescaled beforehand.
+///
+/// This is best-effort: it has not been checked against hashes published by
+/// the server, which may also hash fields that are not part of websocket
+/// snapshots. A mismatch is therefore not proof of corruption; treat it as a
+/// hint, as [`BookEvent::verify_hash`] does.
+pub fn compute_book_hash(
+    market: &str,
+    asset_id: &str,
+    timestamp: &str,
+    bids: &[PriceLevel],
+    asks: &[PriceLevel],
+) -> String {
+    use sha1::{Digest, Sha1};
+
+    let book = HashedBook {
+        market,
+        asset_id,
+        timestamp,
+        bids,
+        asks,
+        hash: "",
+    };
+    // Serializing borrowed strings and decimals cannot fail
+    let json = serde_json::to_vec(&book).unwrap_or_default();
+    alloy_primitives::hex::encode(Sha1::digest(json))
+}
+
 /// Incremental order book update event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceChangeEvent {
@@ -64,6 +156,40 @@ pub struct PriceChangeEvent {
     pub price_changes: Vec<PriceChange>,
 }
 
+impl PriceChangeEvent {
+    /// Merge several price change events into one
+    ///
+    /// Changes to the same asset, side and price level collapse into the last
+    /// one seen, so applying the result to a book is equivalent to applying every
+    /// event in order. Levels are kept in order of first appearance and changes
+    /// for different assets are never merged together.
+    ///
+    /// The merged event takes its `market` from the first event and its `timestamp`
+    /// and `hash` from the last one. An empty slice yields an event with no changes.
+    pub fn coalesce(events: &[PriceChangeEvent]) -> PriceChangeEvent {
+        let mut price_changes: Vec<PriceChange> = Vec::new();
+        let mut index: HashMap<(&str, Side, Decimal), usize> = HashMap::new();
+
+        for change in events.iter().flat_map(|e| &e.price_changes) {
+            let key = (change.asset_id.as_str(), change.side, change.price);
+            match index.get(&key) {
+                Some(&i) => price_changes[i] = change.clone(),
+                None => {
+                    index.insert(key, price_changes.len());
+                    price_changes.push(change.clone());
+                }
+            }
+        }
+
+        PriceChangeEvent {
+            market: events.first().map(|e| e.market.clone()).unwrap_or_default(),
+            timestamp: events.last().and_then(|e| e.timestamp.clone()),
+            hash: events.last().and_then(|e| e.hash.clone()),
+            price_changes,
+        }
+    }
+}
+
 /// Individual price level change
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceChange {
@@ -136,6 +262,27 @@ pub enum UserWsEvent {
     Order(OrderEvent),
 }
 
+impl UserWsEvent {
+    /// Time the server emitted the event
+    ///
+    /// Trade events carry no timestamp, and order events may omit it; both
+    /// return `None`.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`](crate::error::Error::InvalidParameter) if
+    /// the timestamp is not a valid Unix time in milliseconds.
+    pub fn timestamp(&self) -> Result<Option<DateTime<Utc>>> {
+        match self {
+            UserWsEvent::Trade(_) => Ok(None),
+            UserWsEvent::Order(event) => event
+                .timestamp
+                .as_deref()
+                .map(parse_ws_timestamp)
+                .transpose(),
+        }
+    }
+}
+
 /// Trade execution event (when an order is matched)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeEvent {
@@ -216,9 +363,9 @@ pub struct OrderEvent {
     /// Order price
     #[serde(with = "rust_decimal::serde::str")]
     pub price: Decimal,
-    /// Associated trades (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub associate_trades: Option<Vec<serde_json::Value>>,
+    /// Trades that filled this order (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associate_trades: Option<Vec<AssociatedTrade>>,
     /// Outcome (Yes/No)
     pub outcome: String,
     /// Order event type (PLACEMENT, CANCELLATION, etc.)
@@ -241,15 +388,139 @@ pub struct OrderEvent {
     pub timestamp: Option<String>,
 }
 
+/// Trade that filled part of an order, from [`OrderEvent::associate_trades`]
+///
+/// The server sends either bare trade ids or full trade objects; for a bare id
+/// only `id` is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "AssociatedTradeRepr")]
+pub struct AssociatedTrade {
+    /// Trade ID
+    pub id: String,
+    /// Matched size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+    /// Match price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    /// Match timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// Trade status (MATCHED, MINED, CONFIRMED, etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// Wire formats accepted for an associated trade
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AssociatedTradeRepr {
+    Id(String),
+    Trade {
+        id: String,
+        #[serde(default, alias = "size")]
+        amount: Option<Decimal>,
+        #[serde(default)]
+        price: Option<Decimal>,
+        #[serde(default, alias = "match_time")]
+        timestamp: Option<String>,
+        #[serde(default)]
+        status: Option<String>,
+    },
+}
+
+impl From<AssociatedTradeRepr> for AssociatedTrade {
+    fn from(repr: AssociatedTradeRepr) -> Self {
+        match repr {
+            AssociatedTradeRepr::Id(id) => Self {
+                id,
+                amount: None,
+                price: None,
+                timestamp: None,
+                status: None,
+            },
+            AssociatedTradeRepr::Trade {
+                id,
+                amount,
+                price,
+                timestamp,
+                status,
+            } => Self {
+                id,
+                amount,
+                price,
+                timestamp,
+                status,
+            },
+        }
+    }
+}
+
 // ============================================================================
 // WebSocket Subscription Messages
 // ============================================================================
 
 /// Subscription message for market websocket
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+///
+/// Serializes to `{"assets_ids": [...], "type": "market"}`. The server silently
+/// ignores subscriptions without the channel type, so build it with
+/// [`new`](Self::new) or [`default`](Default::default) rather than a struct literal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketSubscription {
     /// List of asset/token IDs to subscribe to
     pub assets_ids: Vec<String>,
+    /// Channel type (always "market")
+    #[serde(rename = "type", default = "market_channel")]
+    pub msg_type: String,
+}
+
+fn market_channel() -> String {
+    "market".to_string()
+}
+
+impl Default for MarketSubscription {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl MarketSubscription {
+    /// Create a subscription to the given asset/token IDs
+    pub fn new(assets_ids: Vec<String>) -> Self {
+        Self {
+            assets_ids,
+            msg_type: market_channel(),
+        }
+    }
+}
+
+/// Change to the assets of an open market websocket subscription
+///
+/// Serializes to `{"assets_ids": [...], "operation": "subscribe"}` (or `"unsubscribe"`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSubscriptionUpdate {
+    /// Asset/token IDs to add or remove
+    pub assets_ids: Vec<String>,
+    /// Whether the assets are added or removed
+    pub operation: SubscriptionOperation,
+}
+
+impl MarketSubscriptionUpdate {
+    /// Create an update adding or removing the given asset/token IDs
+    pub fn new(assets_ids: Vec<String>, operation: SubscriptionOperation) -> Self {
+        Self {
+            assets_ids,
+            operation,
+        }
+    }
+}
+
+/// Operation of a [`MarketSubscriptionUpdate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionOperation {
+    Subscribe,
+    Unsubscribe,
 }
 
 /// Authentication message for user websocket
@@ -260,6 +531,9 @@ pub struct UserAuthentication {
     pub msg_type: String,
     /// Authentication credentials
     pub auth: AuthCredentials,
+    /// Condition IDs to restrict events to (empty = all markets)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub markets: Vec<String>,
 }
 
 /// Authentication credentials for user websocket
@@ -284,6 +558,201 @@ impl UserAuthentication {
                 secret,
                 passphrase,
             },
+            markets: Vec::new(),
+        }
+    }
+
+    /// Only receive events for the given markets (condition IDs)
+    pub fn with_markets(mut self, markets: Vec<String>) -> Self {
+        self.markets = markets;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn change(asset_id: &str, side: Side, price: Decimal, size: Decimal) -> PriceChange {
+        PriceChange {
+            asset_id: asset_id.to_string(),
+            side,
+            price,
+            size,
+        }
+    }
+
+    fn event(timestamp: &str, price_changes: Vec<PriceChange>) -> PriceChangeEvent {
+        PriceChangeEvent {
+            market: "0xmarket".to_string(),
+            timestamp: Some(timestamp.to_string()),
+            hash: None,
+            price_changes,
         }
     }
+
+    #[test]
+    fn test_coalesce_last_wins() {
+        let events = vec![
+            event(
+                "1",
+                vec![
+                    change("A", Side::Buy, dec!(0.50), dec!(10)),
+                    change("A", Side::Sell, dec!(0.55), dec!(5)),
+                ],
+            ),
+            event("2", vec![change("A", Side::Buy, dec!(0.50), dec!(0))]),
+            event("3", vec![change("A", Side::Buy, dec!(0.50), dec!(7))]),
+        ];
+
+        let merged = PriceChangeEvent::coalesce(&events);
+        assert_eq!(merged.timestamp.as_deref(), Some("3"));
+        assert_eq!(merged.price_changes.len(), 2);
+        assert_eq!(merged.price_changes[0].size, dec!(7));
+        assert_eq!(merged.price_changes[1].side, Side::Sell);
+    }
+
+    #[test]
+    fn test_coalesce_keeps_assets_separate() {
+        let events = vec![
+            event("1", vec![change("A", Side::Buy, dec!(0.50), dec!(10))]),
+            event("2", vec![change("B", Side::Buy, dec!(0.50), dec!(20))]),
+        ];
+
+        let merged = PriceChangeEvent::coalesce(&events);
+        assert_eq!(merged.price_changes.len(), 2);
+        assert_eq!(merged.price_changes[0].asset_id, "A");
+        assert_eq!(merged.price_changes[1].asset_id, "B");
+    }
+
+    #[test]
+    fn test_user_authentication_markets() {
+        let auth = UserAuthentication::new("key".into(), "secret".into(), "pass".into());
+        let json = serde_json::to_value(&auth).unwrap();
+        assert!(json.get("markets").is_none());
+
+        let json = serde_json::to_value(auth.with_markets(vec!["0xabc".to_string()])).unwrap();
+        assert_eq!(json["type"], "user");
+        assert_eq!(json["markets"], serde_json::json!(["0xabc"]));
+    }
+
+    #[test]
+    fn test_coalesce_empty() {
+        let merged = PriceChangeEvent::coalesce(&[]);
+        assert!(merged.price_changes.is_empty());
+        assert!(merged.timestamp.is_none());
+    }
+
+    #[test]
+    fn test_order_event_associate_trades() {
+        let json = serde_json::json!({
+            "event_type": "order",
+            "id": "0xorder",
+            "market": "0xmarket",
+            "asset_id": "123",
+            "side": "BUY",
+            "original_size": "10",
+            "size_matched": "4",
+            "price": "0.5",
+            "associate_trades": [
+                "trade-1",
+                {"id": "trade-2", "size": "4", "price": "0.5", "status": "MATCHED"}
+            ],
+            "outcome": "Yes",
+            "type": "UPDATE",
+            "order_type": "GTC",
+            "status": "LIVE",
+            "maker_address": "0xmaker"
+        });
+
+        let UserWsEvent::Order(order) = serde_json::from_value(json).unwrap() else {
+            panic!("expected order event");
+        };
+        let trades = order.associate_trades.unwrap();
+        assert_eq!(trades[0].id, "trade-1");
+        assert_eq!(trades[0].amount, None);
+        assert_eq!(trades[1].id, "trade-2");
+        assert_eq!(trades[1].amount, Some(dec!(4)));
+        assert_eq!(trades[1].price, Some(dec!(0.5)));
+        assert_eq!(trades[1].status.as_deref(), Some("MATCHED"));
+    }
+
+    #[test]
+    fn test_market_subscription_wire_format() {
+        let subscription = MarketSubscription::new(vec!["123".to_string(), "456".to_string()]);
+        assert_eq!(
+            serde_json::to_string(&subscription).unwrap(),
+            r#"{"assets_ids":["123","456"],"type":"market"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&MarketSubscription::default()).unwrap(),
+            r#"{"assets_ids":[],"type":"market"}"#
+        );
+        let parsed: MarketSubscription = serde_json::from_str(r#"{"assets_ids":["1"]}"#).unwrap();
+        assert_eq!(parsed.msg_type, "market");
+
+        let update = MarketSubscriptionUpdate::new(
+            vec!["789".to_string()],
+            SubscriptionOperation::Unsubscribe,
+        );
+        assert_eq!(
+            serde_json::to_string(&update).unwrap(),
+            r#"{"assets_ids":["789"],"operation":"unsubscribe"}"#
+        );
+    }
+
+    #[test]
+    fn test_book_hash() {
+        let mut book = BookEvent {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            timestamp: "1000".to_string(),
+            hash: String::new(),
+            bids: vec![PriceLevel {
+                price: dec!(0.48),
+                size: dec!(10),
+            }],
+            asks: vec![PriceLevel {
+                price: dec!(0.52),
+                size: dec!(15.5),
+            }],
+            last_trade_price: None,
+        };
+
+        // SHA-1 of the documented serialization, computed outside this crate (not
+        // a hash published by the server):
+        // {"market":"0xmarket","asset_id":"123","timestamp":"1000","bids":[{"price":"0.48","size":"10"}],"asks":[{"price":"0.52","size":"15.5"}],"hash":""}
+        let hash = compute_book_hash(
+            &book.market,
+            &book.asset_id,
+            &book.timestamp,
+            &book.bids,
+            &book.asks,
+        );
+        assert_eq!(hash, "c920491e07c3082830d1b3cc6d6e7bed5257aee2");
+
+        book.hash = format!("0x{}", hash.to_uppercase());
+        assert!(book.verify_hash());
+
+        book.asks[0].size = dec!(15);
+        assert!(!book.verify_hash());
+    }
+
+    #[test]
+    fn test_event_timestamp() {
+        let timed = WsEvent::PriceChange(event("1700000000123", vec![]));
+        let timestamp = timed.timestamp().unwrap().unwrap();
+        assert_eq!(timestamp.timestamp_millis(), 1_700_000_000_123);
+
+        let mut untimed = event("1", vec![]);
+        untimed.timestamp = None;
+        assert!(WsEvent::PriceChange(untimed).timestamp().unwrap().is_none());
+
+        let garbled = WsEvent::PriceChange(event("soon", vec![]));
+        assert!(matches!(
+            garbled.timestamp(),
+            Err(crate::error::Error::InvalidParameter(_))
+        ));
+    }
 }
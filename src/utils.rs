@@ -1,25 +1,26 @@
+//! General helpers shared across the crate.
+
+pub mod time;
+
+use crate::clock::{Clock, SystemClock};
 use crate::error::{Error, Result};
 use base64::{engine::general_purpose::URL_SAFE, Engine};
 use hmac::{Hmac, Mac};
 use serde::Serialize;
 use sha2::Sha256;
-use std::time::{SystemTime, UNIX_EPOCH};
 
 type HmacSha256 = Hmac<Sha256>;
 
 /// Get current Unix timestamp in seconds
-pub fn get_current_unix_time_secs() -> Result<u64> {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .map_err(|e| Error::Config(format!("System time error: {}", e)))
+pub(crate) fn get_current_unix_time_secs() -> Result<u64> {
+    SystemClock.unix_time_secs()
 }
 
 /// Build HMAC-SHA256 signature for L2 authentication
 ///
 /// This generates the signature required for authenticated API requests
 /// using the API secret key.
-pub fn build_hmac_signature<T>(
+pub(crate) fn build_hmac_signature<T>(
     secret: &str,
     timestamp: u64,
     method: &str,
@@ -0,0 +1,89 @@
+//! Conversions between the timestamp representations used across the APIs.
+//!
+//! - Order expirations and L2 header timestamps are Unix seconds (`u64`)
+//! - WebSocket events carry Unix milliseconds as strings
+//! - Market dates are [`DateTime<Utc>`]
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{Error, Result};
+
+/// Convert a Unix timestamp in milliseconds to seconds, rounding down
+pub fn to_unix_secs(millis: u64) -> u64 {
+    millis / 1000
+}
+
+/// Convert a Unix timestamp in seconds to milliseconds
+///
+/// # Errors
+/// Returns [`Error::ArithmeticOverflow`] if the result does not fit in a `u64`.
+pub fn to_unix_millis(secs: u64) -> Result<u64> {
+    secs.checked_mul(1000)
+        .ok_or_else(|| Error::ArithmeticOverflow(format!("{} seconds in milliseconds", secs)))
+}
+
+/// Parse a WebSocket event timestamp (Unix milliseconds as a string)
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::utils::time::parse_ws_timestamp;
+///
+/// let datetime = parse_ws_timestamp("1700000000123").unwrap();
+/// assert_eq!(datetime.timestamp_millis(), 1_700_000_000_123);
+/// ```
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if the string is not an in-range integer.
+pub fn parse_ws_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
+    timestamp
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .ok_or_else(|| Error::InvalidParameter(format!("Invalid timestamp: {}", timestamp)))
+}
+
+/// Convert a datetime to a Unix timestamp in seconds
+///
+/// # Errors
+/// Returns [`Error::Config`] if the datetime is before the Unix epoch.
+pub fn datetime_to_unix_secs(datetime: &DateTime<Utc>) -> Result<u64> {
+    let timestamp = datetime.timestamp();
+    u64::try_from(timestamp)
+        .map_err(|_| Error::Config(format!("Time {} is before the Unix epoch", timestamp)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_conversions() {
+        assert_eq!(to_unix_secs(1_700_000_000_999), 1_700_000_000);
+        assert_eq!(to_unix_millis(1_700_000_000).unwrap(), 1_700_000_000_000);
+        assert!(matches!(
+            to_unix_millis(u64::MAX),
+            Err(Error::ArithmeticOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_ws_timestamp() {
+        let datetime = parse_ws_timestamp(" 1700000000123 ").unwrap();
+        assert_eq!(datetime_to_unix_secs(&datetime).unwrap(), 1_700_000_000);
+        assert!(matches!(
+            parse_ws_timestamp("not a number"),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_datetime_before_epoch() {
+        let datetime = DateTime::from_timestamp(-1, 0).unwrap();
+        assert!(matches!(
+            datetime_to_unix_secs(&datetime),
+            Err(Error::Config(_))
+        ));
+    }
+}
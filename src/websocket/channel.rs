@@ -0,0 +1,209 @@
+use futures_util::{stream, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+/// What to do with an incoming event when the channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Stop reading from the socket until the consumer catches up
+    #[default]
+    Block,
+    /// Discard the oldest buffered event to make room
+    DropOldest,
+    /// Discard the incoming event
+    DropNewest,
+}
+
+/// Bounded buffer between a WebSocket connection and its consumer
+///
+/// A background task reads the socket into a queue of at most `capacity`
+/// events; `on_full` decides what happens when the consumer falls behind.
+/// Events discarded by [`OverflowPolicy::DropOldest`] or
+/// [`OverflowPolicy::DropNewest`] are counted on the subscription handle.
+///
+/// A capacity of zero is treated as one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WsChannelConfig {
+    /// Maximum number of buffered events
+    pub capacity: usize,
+    /// Behavior when the buffer is full
+    pub on_full: OverflowPolicy,
+}
+
+impl Default for WsChannelConfig {
+    /// 10 000 events, blocking when full so no event is lost
+    fn default() -> Self {
+        Self {
+            capacity: 10_000,
+            on_full: OverflowPolicy::Block,
+        }
+    }
+}
+
+struct Queue<T> {
+    items: VecDeque<T>,
+    /// Whether the producer has finished
+    closed: bool,
+}
+
+struct Shared<T> {
+    queue: Mutex<Queue<T>>,
+    capacity: usize,
+    /// Signalled when an item is pushed or the queue is closed
+    pushed: Notify,
+    /// Signalled when an item is popped
+    popped: Notify,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Queue<T>> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    async fn push(&self, item: T, policy: OverflowPolicy, dropped: &AtomicU64) {
+        loop {
+            {
+                let mut queue = self.lock();
+                if queue.items.len() < self.capacity {
+                    queue.items.push_back(item);
+                    break;
+                }
+                match policy {
+                    OverflowPolicy::Block => {}
+                    OverflowPolicy::DropOldest => {
+                        queue.items.pop_front();
+                        queue.items.push_back(item);
+                        dropped.fetch_add(1, Ordering::Relaxed);
+                        break;
+                    }
+                    OverflowPolicy::DropNewest => {
+                        dropped.fetch_add(1, Ordering::Relaxed);
+                        return;
+                    }
+                }
+            }
+            self.popped.notified().await;
+        }
+        self.pushed.notify_one();
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.pushed.notify_one();
+    }
+
+    async fn pop(&self) -> Option<T> {
+        loop {
+            {
+                let mut queue = self.lock();
+                if let Some(item) = queue.items.pop_front() {
+                    drop(queue);
+                    self.popped.notify_one();
+                    return Some(item);
+                }
+                if queue.closed {
+                    return None;
+                }
+            }
+            self.pushed.notified().await;
+        }
+    }
+}
+
+/// Stream of events forwarded by a background task, which is stopped on drop
+struct ChannelStream<T> {
+    inner: Pin<Box<dyn Stream<Item = T> + Send>>,
+    task: JoinHandle<()>,
+}
+
+impl<T> Stream for ChannelStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> Drop for ChannelStream<T> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Read `source` on a background task into a bounded buffer
+///
+/// Must be called from within a tokio runtime.
+pub(crate) fn buffered<T, S>(
+    source: S,
+    config: WsChannelConfig,
+    dropped: Arc<AtomicU64>,
+) -> Pin<Box<dyn Stream<Item = T> + Send>>
+where
+    T: Send + 'static,
+    S: Stream<Item = T> + Send + 'static,
+{
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(Queue {
+            items: VecDeque::new(),
+            closed: false,
+        }),
+        capacity: config.capacity.max(1),
+        pushed: Notify::new(),
+        popped: Notify::new(),
+    });
+
+    let producer = shared.clone();
+    let task = tokio::spawn(async move {
+        let mut source = Box::pin(source);
+        while let Some(item) = source.next().await {
+            producer.push(item, config.on_full, &dropped).await;
+        }
+        producer.close();
+    });
+
+    let inner = stream::unfold(shared, |shared| async move {
+        let item = shared.pop().await?;
+        Some((item, shared))
+    });
+
+    Box::pin(ChannelStream {
+        inner: Box::pin(inner),
+        task,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn drain(policy: OverflowPolicy) -> (Vec<u32>, u64) {
+        let dropped = Arc::new(AtomicU64::new(0));
+        let config = WsChannelConfig {
+            capacity: 2,
+            on_full: policy,
+        };
+        let mut stream = buffered(stream::iter(1..=5), config, dropped.clone());
+
+        // Let the producer run ahead of the consumer
+        tokio::task::yield_now().await;
+        tokio::task::yield_now().await;
+
+        let mut items = Vec::new();
+        while let Some(item) = stream.next().await {
+            items.push(item);
+        }
+        (items, dropped.load(Ordering::Relaxed))
+    }
+
+    #[tokio::test]
+    async fn test_overflow_policies() {
+        assert_eq!(drain(OverflowPolicy::Block).await, (vec![1, 2, 3, 4, 5], 0));
+        assert_eq!(drain(OverflowPolicy::DropOldest).await, (vec![4, 5], 3));
+        assert_eq!(drain(OverflowPolicy::DropNewest).await, (vec![1, 2], 3));
+    }
+}
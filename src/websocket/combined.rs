@@ -0,0 +1,117 @@
+use futures_util::{stream, Stream, StreamExt};
+use std::pin::Pin;
+
+use super::{MarketWsClient, ReconnectConfig, ReconnectingStream, UserWsClient};
+use crate::error::Result;
+use crate::types::{ApiCreds, UserWsEvent, WsEvent};
+
+/// Event from either the market or the user channel
+// Sized like UserWsEvent; boxing would only add an allocation per event
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum CombinedWsEvent {
+    /// Market data event (order book, price changes, trades)
+    Market(WsEvent),
+    /// Authenticated user event (own trades and orders)
+    User(UserWsEvent),
+}
+
+/// WebSocket client merging the market and user channels into one stream
+///
+/// Manages one [`MarketWsClient`] and one [`UserWsClient`] connection, each wrapped
+/// in its own [`ReconnectingStream`]. A disconnect on one channel is retried with
+/// backoff without interrupting the other.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polymarket_rs::types::ApiCreds;
+/// use polymarket_rs::websocket::{CombinedWsClient, CombinedWsEvent};
+///
+/// # async fn example(creds: ApiCreds) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = CombinedWsClient::new();
+/// let mut stream = client.subscribe(
+///     vec!["token_id".to_string()],
+///     vec!["condition_id".to_string()],
+///     creds,
+/// );
+///
+/// while let Some(event) = stream.next().await {
+///     match event? {
+///         CombinedWsEvent::Market(event) => println!("Market: {:?}", event),
+///         CombinedWsEvent::User(event) => println!("User: {:?}", event),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CombinedWsClient {
+    market: MarketWsClient,
+    user: UserWsClient,
+    reconnect_config: ReconnectConfig,
+}
+
+impl CombinedWsClient {
+    /// Create a combined client with the default endpoints
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a combined client from existing market and user clients
+    pub fn with_clients(market: MarketWsClient, user: UserWsClient) -> Self {
+        Self {
+            market,
+            user,
+            reconnect_config: ReconnectConfig::default(),
+        }
+    }
+
+    /// Set the reconnection behavior used for both connections
+    pub fn with_reconnect_config(mut self, config: ReconnectConfig) -> Self {
+        self.reconnect_config = config;
+        self
+    }
+
+    /// Subscribe to market data and user events as a single stream
+    ///
+    /// Connections are established lazily when the stream is first polled and
+    /// re-established independently after a disconnect. Events from both channels
+    /// are interleaved in arrival order.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_assets` - Token IDs to stream market data for (empty = no market connection)
+    /// * `user_markets` - Condition IDs to receive user events for (empty = all markets)
+    /// * `creds` - API credentials for the user channel
+    pub fn subscribe(
+        &self,
+        market_assets: Vec<String>,
+        user_markets: Vec<String>,
+        creds: ApiCreds,
+    ) -> Pin<Box<dyn Stream<Item = Result<CombinedWsEvent>> + Send>> {
+        let user_client = self.user.clone();
+        let user = ReconnectingStream::new(self.reconnect_config.clone(), move || {
+            let client = user_client.clone();
+            let creds = creds.clone();
+            let markets = user_markets.clone();
+            async move { client.subscribe_to_markets(&creds, markets).await }
+        })
+        .map(|event| event.map(CombinedWsEvent::User));
+
+        if market_assets.is_empty() {
+            return Box::pin(user);
+        }
+
+        let market_client = self.market.clone();
+        let market = ReconnectingStream::new(self.reconnect_config.clone(), move || {
+            let client = market_client.clone();
+            let assets = market_assets.clone();
+            async move { client.subscribe(assets).await }
+        })
+        .map(|event| event.map(CombinedWsEvent::Market));
+
+        Box::pin(stream::select(market, user))
+    }
+}
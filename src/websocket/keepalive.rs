@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use futures_util::{Sink, SinkExt, Stream};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::error::{Error, Result};
+
+/// Application-level keepalive for WebSocket connections
+///
+/// Every `interval`, a `PING` text message is sent and the server is expected to
+/// answer with `PONG` within `timeout`. When it does not, the stream yields
+/// [`Error::KeepaliveTimeout`] and ends, so a
+/// [`ReconnectingStream`](super::ReconnectingStream) reconnects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepaliveConfig {
+    /// Delay between pings
+    pub interval: Duration,
+    /// Maximum wait for the pong answering a ping
+    pub timeout: Duration,
+}
+
+impl Default for KeepaliveConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(10),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Time of the last pong received on a connection
+pub(crate) type LastPong = Arc<RwLock<Option<DateTime<Utc>>>>;
+
+/// Record the time of a pong, sent either as text or as a control frame
+pub(crate) fn record_pong(message: &Message, last_pong: &LastPong) {
+    let is_pong = match message {
+        Message::Text(text) => text.trim().eq_ignore_ascii_case("pong"),
+        Message::Pong(_) => true,
+        _ => false,
+    };
+    if is_pong {
+        *last_pong.write().unwrap_or_else(PoisonError::into_inner) = Some(Utc::now());
+    }
+}
+
+/// Send pings on `sink` in the background, failing `events` when a pong is missed
+///
+/// `last_pong` must be updated with [`record_pong`] as messages are read.
+/// Must be called from within a tokio runtime.
+pub(crate) fn with_keepalive<T, S, K>(
+    events: S,
+    sink: Arc<Mutex<Option<K>>>,
+    last_pong: LastPong,
+    config: KeepaliveConfig,
+) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>>
+where
+    T: Send + 'static,
+    S: Stream<Item = Result<T>> + Send + Unpin + 'static,
+    K: Sink<Message> + Send + Unpin + 'static,
+    K::Error: std::fmt::Display,
+{
+    let (failed_tx, failed_rx) = oneshot::channel();
+
+    let task = tokio::spawn(async move {
+        let error = loop {
+            sleep(config.interval).await;
+
+            let sent = Utc::now();
+            if let Some(sink) = sink.lock().await.as_mut() {
+                if let Err(e) = sink.send(Message::Text("PING".to_string())).await {
+                    break Error::WebSocket(e.to_string());
+                }
+            }
+
+            sleep(config.timeout).await;
+            let answered = last_pong
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .is_some_and(|pong| pong >= sent);
+            if !answered {
+                break Error::KeepaliveTimeout;
+            }
+        };
+        let _ = failed_tx.send(error);
+    });
+
+    Box::pin(KeepaliveStream {
+        events,
+        failed: Some(failed_rx),
+        done: false,
+        task,
+    })
+}
+
+/// Event stream ended by a keepalive failure; stops the ping task on drop
+struct KeepaliveStream<S> {
+    events: S,
+    failed: Option<oneshot::Receiver<Error>>,
+    done: bool,
+    task: JoinHandle<()>,
+}
+
+impl<T, S> Stream for KeepaliveStream<S>
+where
+    S: Stream<Item = Result<T>> + Unpin,
+{
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<T>>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        if let Some(failed) = self.failed.as_mut() {
+            match Pin::new(failed).poll(cx) {
+                Poll::Ready(Ok(error)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+                Poll::Ready(Err(_)) => self.failed = None,
+                Poll::Pending => {}
+            }
+        }
+
+        Pin::new(&mut self.events).poll_next(cx)
+    }
+}
+
+impl<S> Drop for KeepaliveStream<S> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -1,31 +1,183 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{stream, SinkExt, Stream, StreamExt};
+use std::collections::HashSet;
 use std::pin::Pin;
-use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
-
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio::time::{timeout_at, Instant};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use super::channel::buffered;
+use super::keepalive::{record_pong, with_keepalive, LastPong};
+use super::{KeepaliveConfig, ReconnectConfig, ReconnectingStream, WsChannelConfig};
+use crate::client::DataClient;
 use crate::error::{Error, Result};
-use crate::types::{MarketSubscription, WsEvent};
+use crate::types::{
+    MarketSubscription, MarketSubscriptionUpdate, Position, SubscriptionOperation, WsEvent,
+};
+
+type WsConnection = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type WsSink = SplitSink<WsConnection, Message>;
+type EventStream = Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>;
 
-/// Handle for querying WebSocket subscription state
+/// Handle for querying and updating a market WebSocket subscription
 ///
-/// This handle provides read-only access to the current token IDs
-/// being subscribed to.
+/// The handle tracks the token IDs currently subscribed to and can add or remove
+/// assets on the open connection with [`add_assets`](Self::add_assets) and
+/// [`remove_assets`](Self::remove_assets), without reconnecting.
 ///
-/// **Note**: Polymarket does not support updating subscriptions on an
-/// existing WebSocket connection. To change subscriptions, you must
-/// close the connection and create a new one with the updated token list.
+/// Handles returned by [`MarketWsClient::subscribe_reconnecting`] follow the
+/// stream across reconnects: every new connection subscribes to the current set.
 #[derive(Clone)]
 pub struct SubscriptionHandle {
     /// Shared state containing current token IDs
     current_tokens: Arc<RwLock<Vec<String>>>,
+    /// Write half of the current connection, if connected
+    sink: Arc<Mutex<Option<WsSink>>>,
+    /// Events discarded by the client's channel overflow policy
+    dropped: Arc<AtomicU64>,
+    /// Time of the last pong received
+    last_pong: LastPong,
 }
 
 impl SubscriptionHandle {
+    fn new(token_ids: Vec<String>) -> Self {
+        Self {
+            current_tokens: Arc::new(RwLock::new(token_ids)),
+            sink: Arc::new(Mutex::new(None)),
+            dropped: Arc::new(AtomicU64::new(0)),
+            last_pong: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Time the server last answered a keepalive ping
+    ///
+    /// `None` until the first pong. Only updated when the client was configured
+    /// with [`MarketWsClient::with_keepalive`].
+    pub fn last_pong(&self) -> Option<DateTime<Utc>> {
+        *self
+            .last_pong
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Number of events discarded because the consumer fell behind
+    ///
+    /// Always zero unless the client was configured with a dropping
+    /// [`OverflowPolicy`](super::OverflowPolicy) through
+    /// [`MarketWsClient::with_channel_config`]. The count accumulates across reconnects.
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
     /// Get the current token IDs being subscribed to
     pub async fn current_tokens(&self) -> Vec<String> {
-        self.current_tokens.read().await.clone()
+        self.subscribed_assets()
+    }
+
+    /// Get the asset (token) IDs this subscription covers
+    pub fn subscribed_assets(&self) -> Vec<String> {
+        self.current_tokens
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Check whether an asset (token) ID is part of this subscription
+    pub fn is_subscribed(&self, asset: &str) -> bool {
+        self.current_tokens
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .any(|token| token == asset)
+    }
+
+    /// Subscribe to more assets on the open connection
+    ///
+    /// Adding an asset that is already subscribed is a no-op: it is neither
+    /// duplicated in the tracked set nor sent to the server again. If every asset
+    /// is already subscribed, no frame is sent.
+    ///
+    /// The tracked set is updated even when there is no open connection, so
+    /// a reconnecting subscription picks the assets up on its next connect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription frame cannot be sent.
+    pub async fn add_assets(&self, asset_ids: Vec<String>) -> Result<()> {
+        let added = {
+            let mut tokens = self
+                .current_tokens
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            let added = new_assets(&tokens, asset_ids);
+            tokens.extend(added.iter().cloned());
+            added
+        };
+        self.send_update(added, SubscriptionOperation::Subscribe)
+            .await
+    }
+
+    /// Unsubscribe from assets on the open connection
+    ///
+    /// Assets that are not subscribed are ignored. If none of the assets are
+    /// subscribed, no frame is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the unsubscription frame cannot be sent.
+    pub async fn remove_assets(&self, asset_ids: Vec<String>) -> Result<()> {
+        let removed = {
+            let mut tokens = self
+                .current_tokens
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            let removed: Vec<String> = tokens
+                .iter()
+                .filter(|token| asset_ids.contains(token))
+                .cloned()
+                .collect();
+            tokens.retain(|token| !removed.contains(token));
+            removed
+        };
+        self.send_update(removed, SubscriptionOperation::Unsubscribe)
+            .await
+    }
+
+    /// Send a subscription update over the current connection, if any
+    async fn send_update(
+        &self,
+        asset_ids: Vec<String>,
+        operation: SubscriptionOperation,
+    ) -> Result<()> {
+        if asset_ids.is_empty() {
+            return Ok(());
+        }
+
+        let update = serde_json::to_string(&MarketSubscriptionUpdate::new(asset_ids, operation))?;
+        match self.sink.lock().await.as_mut() {
+            Some(sink) => sink
+                .send(Message::Text(update))
+                .await
+                .map_err(|e| Error::WebSocket(e.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Asset IDs from `asset_ids` not yet in `current`, deduplicated in input order
+fn new_assets(current: &[String], asset_ids: Vec<String>) -> Vec<String> {
+    let mut added: Vec<String> = Vec::new();
+    for asset in asset_ids {
+        if !current.contains(&asset) && !added.contains(&asset) {
+            added.push(asset);
+        }
     }
+    added
 }
 
 /// WebSocket client for streaming market data (order book updates)
@@ -41,84 +193,150 @@ impl SubscriptionHandle {
 /// For Rust, the recommended approach is to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
 /// which automatically handles connection resets and reconnects with exponential backoff.
 /// This is more robust than manual ping/pong management.
+///
+/// # Buffering
+///
+/// By default events are read from the socket only as the stream is polled, so a
+/// slow consumer leaves them queued in the connection. Use
+/// [`with_channel_config`](Self::with_channel_config) to read on a background
+/// task into a bounded buffer with an explicit overflow policy instead.
+///
+/// # Keepalive
+///
+/// Load balancers may drop connections on quiet markets. Use
+/// [`with_keepalive`](Self::with_keepalive) to ping the server periodically and
+/// fail the stream when it stops answering.
 #[derive(Debug, Clone)]
 pub struct MarketWsClient {
     ws_url: String,
+    channel: Option<WsChannelConfig>,
+    keepalive: Option<KeepaliveConfig>,
 }
 
-/// Parse a WebSocket message into a WsEvent
+/// Parse a WebSocket message into WsEvents
 ///
 /// This is a helper function that handles the parsing logic shared by both
-/// subscribe() and subscribe_with_handle() methods.
+/// subscribe() and subscribe_with_handle() methods. The server batches events
+/// (e.g. the initial book snapshots of every subscribed asset) into a JSON array,
+/// so a single message can yield several events.
 fn parse_ws_message(
     msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
-) -> Option<Result<WsEvent>> {
+) -> Vec<Result<WsEvent>> {
     match msg {
         Ok(Message::Text(text)) => {
             // Skip empty or whitespace-only messages
             let trimmed = text.trim();
             if trimmed.is_empty() {
-                return None;
+                return Vec::new();
             }
 
             // Skip PING/PONG messages sent as text (some servers do this)
             if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
-                return None;
+                return Vec::new();
             }
 
             // The server can send either a single object or an array
             // Try to parse as array first
             if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                // Got an array, take the first event
-                if let Some(first) = events.first() {
-                    match serde_json::from_value::<WsEvent>(first.clone()) {
-                        Ok(event) => return Some(Ok(event)),
-                        Err(e) => return Some(Err(Error::Json(e))),
-                    }
-                } else {
-                    // Empty array, ignore
-                    return None;
-                }
+                // Got an array (possibly empty), yield every event
+                return events
+                    .into_iter()
+                    .map(|event| serde_json::from_value::<WsEvent>(event).map_err(Error::Json))
+                    .collect();
             }
 
             // Try parsing as single object
             match serde_json::from_str::<WsEvent>(&text) {
-                Ok(event) => Some(Ok(event)),
+                Ok(event) => vec![Ok(event)],
                 Err(e) => {
                     // Log unexpected message format for debugging
                     log::warn!(
                         "Unexpected WebSocket message (first 200 chars): {}",
                         &text.chars().take(200).collect::<String>()
                     );
-                    Some(Err(Error::Json(e)))
+                    vec![Err(Error::Json(e))]
                 }
             }
         }
         Ok(Message::Close(_)) => {
             // Connection closed gracefully
-            Some(Err(Error::ConnectionClosed))
+            vec![Err(Error::ConnectionClosed)]
         }
         Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
             // Ignore ping/pong frames (handled automatically)
-            None
+            Vec::new()
         }
         Ok(Message::Binary(_)) => {
             // Unexpected binary message
-            Some(Err(Error::WebSocket(
+            vec![Err(Error::WebSocket(
                 "Unexpected binary message".to_string(),
-            )))
+            ))]
         }
         Ok(Message::Frame(_)) => {
             // Raw frame (shouldn't happen)
-            None
+            Vec::new()
         }
         Err(e) => {
             // WebSocket error
-            Some(Err(Error::WebSocket(e.to_string())))
+            vec![Err(Error::WebSocket(e.to_string()))]
         }
     }
 }
 
+/// Wait until every asset has produced a book snapshot
+///
+/// Events received while waiting are replayed at the start of the returned stream.
+async fn await_snapshots<S>(
+    mut stream: S,
+    token_ids: &[String],
+    timeout: Duration,
+) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>>
+where
+    S: Stream<Item = Result<WsEvent>> + Send + Unpin + 'static,
+{
+    let mut pending: HashSet<&str> = token_ids.iter().map(String::as_str).collect();
+    let mut buffered = Vec::new();
+    let deadline = Instant::now() + timeout;
+
+    while !pending.is_empty() {
+        match timeout_at(deadline, stream.next()).await {
+            Ok(Some(Err(Error::ConnectionClosed))) | Ok(None) => {
+                return Err(Error::ConnectionClosed)
+            }
+            Ok(Some(item)) => {
+                if let Ok(WsEvent::Book(ref book)) = item {
+                    pending.remove(book.asset_id.as_str());
+                }
+                buffered.push(item);
+            }
+            Err(_) => break,
+        }
+    }
+
+    if !pending.is_empty() {
+        let mut assets: Vec<String> = Vec::new();
+        for id in token_ids {
+            if pending.remove(id.as_str()) {
+                assets.push(id.clone());
+            }
+        }
+        return Err(Error::SubscriptionTimeout { assets });
+    }
+
+    Ok(Box::pin(stream::iter(buffered).chain(stream)))
+}
+
+/// Unique token IDs of positions that still trade, in first-seen order
+fn position_asset_ids(positions: &[Position]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    positions
+        .iter()
+        .filter(|position| !position.redeemable)
+        .filter(|position| seen.insert(position.asset.as_str()))
+        .map(|position| position.asset.clone())
+        .collect()
+}
+
 impl MarketWsClient {
     /// Default WebSocket URL for market data
     const DEFAULT_WS_URL: &'static str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
@@ -127,6 +345,8 @@ impl MarketWsClient {
     pub fn new() -> Self {
         Self {
             ws_url: Self::DEFAULT_WS_URL.to_string(),
+            channel: None,
+            keepalive: None,
         }
     }
 
@@ -134,16 +354,81 @@ impl MarketWsClient {
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            channel: None,
+            keepalive: None,
         }
     }
 
-    /// Subscribe to market updates with a handle to query subscription state
+    /// Buffer events in a bounded channel filled by a background task
     ///
-    /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
-    /// to query which token IDs are currently subscribed.
+    /// Applies to every stream created by this client afterwards. The number of
+    /// events discarded by the overflow policy is reported by
+    /// [`SubscriptionHandle::dropped_events`].
+    pub fn with_channel_config(mut self, config: WsChannelConfig) -> Self {
+        self.channel = Some(config);
+        self
+    }
+
+    /// Ping the server periodically, failing the stream when a pong is missed
     ///
-    /// **Note**: Polymarket does not support updating subscriptions on an existing connection.
-    /// To change subscriptions, you must close the connection and create a new one.
+    /// Applies to every stream created by this client afterwards. The time of the
+    /// last pong is reported by [`SubscriptionHandle::last_pong`]. When a bounded
+    /// channel blocks on a slow consumer, pongs are not read either, so keep the
+    /// timeout well above the expected consumer stalls.
+    pub fn with_keepalive(mut self, config: KeepaliveConfig) -> Self {
+        self.keepalive = Some(config);
+        self
+    }
+
+    /// Connect and send the initial subscription for `token_ids`
+    async fn connect(&self, token_ids: Vec<String>) -> Result<(WsSink, SplitStream<WsConnection>)> {
+        let (ws_stream, _) = connect_async(&self.ws_url).await?;
+        let (mut write, read) = ws_stream.split();
+
+        let subscription_msg = serde_json::to_string(&MarketSubscription::new(token_ids))?;
+        write
+            .send(Message::Text(subscription_msg))
+            .await
+            .map_err(|e| Error::WebSocket(e.to_string()))?;
+
+        Ok((write, read))
+    }
+
+    /// Parse the read half into events, applying the configured channel and keepalive
+    fn event_stream(
+        &self,
+        read: SplitStream<WsConnection>,
+        handle: &SubscriptionHandle,
+    ) -> EventStream {
+        let last_pong = handle.last_pong.clone();
+        let events = read
+            .inspect(move |msg| {
+                if let Ok(msg) = msg {
+                    record_pong(msg, &last_pong);
+                }
+            })
+            .flat_map(|msg| stream::iter(parse_ws_message(msg)));
+
+        let events: EventStream = match self.channel {
+            Some(config) => buffered(events, config, handle.dropped.clone()),
+            None => Box::pin(events),
+        };
+        match self.keepalive {
+            Some(config) => with_keepalive(
+                events,
+                handle.sink.clone(),
+                handle.last_pong.clone(),
+                config,
+            ),
+            None => events,
+        }
+    }
+
+    /// Subscribe to market updates with a handle to query and update the subscription
+    ///
+    /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
+    /// to query which token IDs are currently subscribed, and to add or remove assets
+    /// on the open connection.
     ///
     /// # Arguments
     ///
@@ -153,7 +438,7 @@ impl MarketWsClient {
     ///
     /// A tuple containing:
     /// - Stream of [`WsEvent`] items
-    /// - [`SubscriptionHandle`] for querying current subscriptions
+    /// - [`SubscriptionHandle`] for querying and updating current subscriptions
     ///
     /// # Events
     ///
@@ -174,38 +459,72 @@ impl MarketWsClient {
         Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>,
         SubscriptionHandle,
     )> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
-
-        // Create subscription message
-        let subscription = MarketSubscription {
-            assets_ids: token_ids.clone(),
-        };
-
-        let subscription_msg = serde_json::to_string(&subscription)?;
-
-        // Send initial subscription message
-        write
-            .send(Message::Text(subscription_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
-
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        let (write, read) = self.connect(token_ids.clone()).await?;
 
-        // Create shared state for current tokens
-        let current_tokens = Arc::new(RwLock::new(token_ids));
+        // Keep the write half in the handle for subscription updates
+        let handle = SubscriptionHandle::new(token_ids);
+        *handle.sink.lock().await = Some(write);
 
-        // Create subscription handle
-        let handle = SubscriptionHandle { current_tokens };
+        let stream = self.event_stream(read, &handle);
+        Ok((stream, handle))
+    }
 
-        // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+    /// Subscribe to market updates, reconnecting automatically when the connection drops
+    ///
+    /// Every (re)connection subscribes to the current assets of the returned handle,
+    /// so assets added or removed through it survive reconnects. The connection is
+    /// only opened once the stream is first polled; updates made before that are
+    /// only tracked.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_ids` - List of token/asset IDs to subscribe to
+    /// * `config` - Reconnection behavior
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::websocket::{MarketWsClient, ReconnectConfig};
+    /// # use futures_util::StreamExt;
+    /// # async fn example() -> polymarket_rs::Result<()> {
+    /// let client = MarketWsClient::new();
+    /// let (mut stream, handle) =
+    ///     client.subscribe_reconnecting(vec!["123".to_string()], ReconnectConfig::default());
+    ///
+    /// if let Some(event) = stream.next().await {
+    ///     println!("Event: {:?}", event);
+    ///     handle.add_assets(vec!["456".to_string()]).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe_reconnecting(
+        &self,
+        token_ids: Vec<String>,
+        config: ReconnectConfig,
+    ) -> (
+        Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>,
+        SubscriptionHandle,
+    ) {
+        let handle = SubscriptionHandle::new(token_ids);
+        let client = self.clone();
+        let subscription = handle.clone();
+
+        let stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            let subscription = subscription.clone();
+            async move {
+                // Hold the sink while connecting so updates made meanwhile are
+                // sent on the new connection rather than lost
+                let mut sink = subscription.sink.lock().await;
+                *sink = None;
+                let (write, read) = client.connect(subscription.subscribed_assets()).await?;
+                *sink = Some(write);
+                Ok(client.event_stream(read, &subscription))
+            }
+        });
 
-        Ok((Box::pin(stream), handle))
+        (Box::pin(stream), handle)
     }
 
     /// Subscribe to market updates for the specified token IDs
@@ -237,32 +556,88 @@ impl MarketWsClient {
         &self,
         token_ids: Vec<String>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
+        let (write, read) = self.connect(token_ids.clone()).await?;
 
-        // Create subscription message
-        let subscription = MarketSubscription {
-            assets_ids: token_ids,
-        };
-
-        let subscription_msg = serde_json::to_string(&subscription)?;
-
-        // Send subscription message
-        write
-            .send(Message::Text(subscription_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
+        // The write half is only kept alive if the keepalive needs it
+        let handle = SubscriptionHandle::new(token_ids);
+        if self.keepalive.is_some() {
+            *handle.sink.lock().await = Some(write);
+        }
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        Ok(self.event_stream(read, &handle))
+    }
 
-        // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+    /// Subscribe to market updates and wait for the subscription to take effect
+    ///
+    /// The server has no explicit subscription acknowledgement, so this waits for
+    /// the initial [`WsEvent::Book`] snapshot of every token before returning. Events
+    /// received while waiting are replayed at the start of the returned stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_ids` - List of token/asset IDs to subscribe to
+    /// * `timeout` - Maximum time to wait for every snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection fails or closes while waiting
+    /// - The subscription message cannot be sent
+    /// - Some assets produce no snapshot within `timeout` ([`Error::SubscriptionTimeout`]
+    ///   lists them)
+    pub async fn subscribe_with_ack(
+        &self,
+        token_ids: Vec<String>,
+        timeout: Duration,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        let stream = self.subscribe(token_ids.clone()).await?;
+        await_snapshots(stream, &token_ids, timeout).await
+    }
 
-        Ok(Box::pin(stream))
+    /// Subscribe to market updates for every token a user holds
+    ///
+    /// Fetches the user's positions and subscribes to their `asset` token IDs.
+    /// Redeemable positions are skipped since their markets have resolved.
+    ///
+    /// This is a snapshot subscription: positions opened or closed afterwards are
+    /// not picked up. Call it again to refresh the set of watched tokens. If the
+    /// user holds no tradeable positions, the returned stream is empty and no
+    /// connection is opened.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_client` - Client used to fetch the positions
+    /// * `user` - The user's wallet address
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polymarket_rs::client::DataClient;
+    /// use polymarket_rs::websocket::MarketWsClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let data_client = DataClient::new("https://data-api.polymarket.com");
+    /// let client = MarketWsClient::new();
+    /// let mut stream = client.watch_positions(&data_client, "0xuser").await?;
+    ///
+    /// while let Some(event) = stream.next().await {
+    ///     println!("Event: {:?}", event?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn watch_positions(
+        &self,
+        data_client: &DataClient,
+        user: &str,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        let positions = data_client.get_positions(user).await?;
+        let token_ids = position_asset_ids(&positions);
+        if token_ids.is_empty() {
+            return Ok(Box::pin(stream::empty()));
+        }
+        self.subscribe(token_ids).await
     }
 }
 
@@ -282,10 +657,158 @@ mod tests {
         assert_eq!(client.ws_url, MarketWsClient::DEFAULT_WS_URL);
     }
 
+    #[test]
+    fn test_position_asset_ids() {
+        let position = |asset: &str, redeemable| Position {
+            asset: asset.to_string(),
+            redeemable,
+            ..Default::default()
+        };
+        let positions = vec![
+            position("1", false),
+            position("2", true),
+            position("3", false),
+            position("1", false),
+        ];
+
+        assert_eq!(position_asset_ids(&positions), vec!["1", "3"]);
+    }
+
+    #[test]
+    fn test_subscription_handle_introspection() {
+        let handle = SubscriptionHandle::new(vec!["1".to_string(), "2".to_string()]);
+
+        assert_eq!(handle.subscribed_assets(), vec!["1", "2"]);
+        assert!(handle.is_subscribed("2"));
+        assert!(!handle.is_subscribed("3"));
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_assets_tracks_set() {
+        let handle = SubscriptionHandle::new(vec!["1".to_string(), "2".to_string()]);
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(new_assets(&ids(&["1"]), ids(&["1", "3", "3"])), vec!["3"]);
+
+        // Not connected: updates are only tracked
+        handle.add_assets(ids(&["2", "3", "3"])).await.unwrap();
+        assert_eq!(handle.subscribed_assets(), vec!["1", "2", "3"]);
+
+        handle.remove_assets(ids(&["1", "4"])).await.unwrap();
+        assert_eq!(handle.subscribed_assets(), vec!["2", "3"]);
+    }
+
     #[test]
     fn test_client_with_custom_url() {
         let custom_url = "wss://custom.example.com/ws";
         let client = MarketWsClient::with_url(custom_url);
         assert_eq!(client.ws_url, custom_url);
     }
+
+    fn book(asset_id: &str) -> Result<WsEvent> {
+        Ok(WsEvent::Book(crate::types::BookEvent {
+            market: "0xmarket".to_string(),
+            asset_id: asset_id.to_string(),
+            timestamp: "1".to_string(),
+            hash: "0xhash".to_string(),
+            bids: vec![],
+            asks: vec![],
+            last_trade_price: None,
+        }))
+    }
+
+    /// Serve a single WebSocket connection, answering `PING` text messages if asked to
+    async fn mock_server(answer_pings: bool) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            while let Some(Ok(msg)) = ws.next().await {
+                if answer_pings && matches!(&msg, Message::Text(text) if text == "PING") {
+                    ws.send(Message::Text("PONG".to_string())).await.unwrap();
+                }
+            }
+        });
+
+        url
+    }
+
+    fn fast_keepalive() -> KeepaliveConfig {
+        KeepaliveConfig {
+            interval: Duration::from_millis(20),
+            timeout: Duration::from_millis(50),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_errors_without_pong() {
+        let client =
+            MarketWsClient::with_url(mock_server(false).await).with_keepalive(fast_keepalive());
+        let (mut stream, handle) = client
+            .subscribe_with_handle(vec!["1".to_string()])
+            .await
+            .unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(1), stream.next())
+            .await
+            .expect("stream should fail within the keepalive timeout");
+        assert!(matches!(result, Some(Err(Error::KeepaliveTimeout))));
+        assert!(handle.last_pong().is_none());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_records_pongs() {
+        let client =
+            MarketWsClient::with_url(mock_server(true).await).with_keepalive(fast_keepalive());
+        let (mut stream, handle) = client
+            .subscribe_with_handle(vec!["1".to_string()])
+            .await
+            .unwrap();
+
+        // Several keepalive rounds pass without an error
+        assert!(
+            tokio::time::timeout(Duration::from_millis(300), stream.next())
+                .await
+                .is_err()
+        );
+        assert!(handle.last_pong().is_some());
+    }
+
+    #[test]
+    fn test_parse_batched_message() {
+        let text = r#"[
+            {"event_type":"book","market":"0xm","asset_id":"A","timestamp":"1","hash":"h","bids":[],"asks":[]},
+            {"event_type":"book","market":"0xm","asset_id":"B","timestamp":"1","hash":"h","bids":[],"asks":[]}
+        ]"#;
+        let events = parse_ws_message(Ok(Message::Text(text.into())));
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|e| matches!(e, Ok(WsEvent::Book(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_await_snapshots_replays_events() {
+        let inner = stream::iter(vec![book("A"), book("B")]).chain(stream::pending());
+        let ids = vec!["A".to_string(), "B".to_string()];
+
+        let mut stream = await_snapshots(Box::pin(inner), &ids, Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(WsEvent::Book(b))) if b.asset_id == "A"));
+        assert!(matches!(stream.next().await, Some(Ok(WsEvent::Book(b))) if b.asset_id == "B"));
+    }
+
+    #[tokio::test]
+    async fn test_await_snapshots_timeout_lists_missing_assets() {
+        let inner = stream::iter(vec![book("A")]).chain(stream::pending());
+        let ids = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+
+        let result = await_snapshots(Box::pin(inner), &ids, Duration::from_millis(20)).await;
+        match result {
+            Err(Error::SubscriptionTimeout { assets }) => assert_eq!(assets, vec!["B", "C"]),
+            _ => panic!("expected subscription timeout"),
+        }
+    }
 }
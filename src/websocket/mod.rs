@@ -4,22 +4,54 @@
 //! - [`MarketWsClient`]: Streams real-time order book updates for markets
 //! - [`UserWsClient`]: Streams authenticated user events (trades and order updates)
 //!
+//! [`CombinedWsClient`] runs both connections and merges them into a single stream
+//! of [`CombinedWsEvent`].
+//!
 //! # Connection Management
 //!
 //! The Polymarket WebSocket server may disconnect idle connections after 1-2 minutes.
 //! For production use, it's recommended to use [`ReconnectingStream`] to automatically
 //! handle disconnections and reconnect with exponential backoff.
+//!
+//! When every tick isn't needed (e.g. for rendering), [`sample_per_asset`] limits
+//! each asset to at most one update per interval.
+//!
+//! A slow consumer can bound memory with [`WsChannelConfig`], which buffers market
+//! events in a fixed-size channel with an [`OverflowPolicy`].
+//!
+//! Recorded market events can be fed back through a
+//! [`LocalOrderBook`](crate::orderbook::LocalOrderBook) with [`replay`] for backtesting.
+//!
+//! # Compression
+//!
+//! Connections do not negotiate `permessage-deflate`. The underlying
+//! `tokio-tungstenite` transport does not implement the extension and rejects
+//! compressed frames, so offering it to a server that accepts would break the
+//! stream. Messages are always exchanged uncompressed, which every server supports.
+//! To reduce traffic for many markets, subscribe only to the assets you need or
+//! thin the stream with [`sample_per_asset`].
 
+mod channel;
+mod combined;
+mod keepalive;
 mod market;
+mod replay;
+mod sample;
 mod stream;
 mod user;
 
+pub use channel::{OverflowPolicy, WsChannelConfig};
+pub use combined::{CombinedWsClient, CombinedWsEvent};
+pub use keepalive::KeepaliveConfig;
 pub use market::{MarketWsClient, SubscriptionHandle};
+pub use replay::replay;
+pub use sample::sample_per_asset;
 pub use stream::{ReconnectConfig, ReconnectingStream};
-pub use user::UserWsClient;
+pub use user::{UserSubscriptionHandle, UserWsClient};
 
 // Re-export commonly used types for convenience
 pub use crate::types::{
-    BookEvent, LastTradePriceEvent, MarketSubscription, OrderEvent, PriceChange, PriceChangeEvent,
-    PriceLevel, TradeEvent, UserAuthentication, UserWsEvent, WsEvent,
+    AssociatedTrade, BookEvent, LastTradePriceEvent, MarketSubscription, MarketSubscriptionUpdate,
+    OrderEvent, PriceChange, PriceChangeEvent, PriceLevel, SubscriptionOperation, TradeEvent,
+    UserAuthentication, UserWsEvent, WsEvent,
 };
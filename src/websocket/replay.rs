@@ -0,0 +1,113 @@
+use crate::orderbook::LocalOrderBook;
+use crate::types::WsEvent;
+
+/// Replay recorded market events through a [`LocalOrderBook`]
+///
+/// Yields each event together with the state of the book after applying it,
+/// which turns a captured stream into a deterministic backtest feed.
+///
+/// The book is created from the first [`WsEvent::Book`] snapshot; events
+/// before it are skipped since there is no book to apply them to. The book
+/// tracks the asset of that snapshot and is left unchanged by events for other
+/// assets, so filter a multi-asset capture per asset before replaying it.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::types::WsEvent;
+/// use polymarket_rs::websocket::replay;
+///
+/// # fn example(recorded: Vec<WsEvent>) {
+/// for (_event, book) in replay(recorded) {
+///     if let (Some(bid), Some(ask)) = (book.best_bid(), book.best_ask()) {
+///         println!("{} / {}", bid.price, ask.price);
+///     }
+/// }
+/// # }
+/// ```
+pub fn replay(
+    events: impl IntoIterator<Item = WsEvent>,
+) -> impl Iterator<Item = (WsEvent, LocalOrderBook)> {
+    let mut book: Option<LocalOrderBook> = None;
+
+    events.into_iter().filter_map(move |event| {
+        match (&mut book, &event) {
+            (None, WsEvent::Book(snapshot)) => book = Some(LocalOrderBook::new(snapshot)),
+            (Some(book), event) => {
+                book.apply(event);
+            }
+            (None, _) => return None,
+        }
+        book.clone().map(|book| (event, book))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BookEvent, PriceChange, PriceChangeEvent, PriceLevel, Side};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn book(timestamp: &str, bid: Decimal, ask: Decimal) -> WsEvent {
+        WsEvent::Book(BookEvent {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            timestamp: timestamp.to_string(),
+            hash: "0xhash".to_string(),
+            bids: vec![PriceLevel {
+                price: bid,
+                size: dec!(10),
+            }],
+            asks: vec![PriceLevel {
+                price: ask,
+                size: dec!(10),
+            }],
+            last_trade_price: None,
+        })
+    }
+
+    fn price_change(side: Side, price: Decimal, size: Decimal) -> WsEvent {
+        WsEvent::PriceChange(PriceChangeEvent {
+            market: "0xmarket".to_string(),
+            timestamp: None,
+            hash: None,
+            price_changes: vec![PriceChange {
+                asset_id: "123".to_string(),
+                side,
+                price,
+                size,
+            }],
+        })
+    }
+
+    #[test]
+    fn test_replay_threads_book_through_events() {
+        let events = vec![
+            price_change(Side::Buy, dec!(0.40), dec!(5)),
+            book("1", dec!(0.48), dec!(0.52)),
+            price_change(Side::Buy, dec!(0.49), dec!(3)),
+            price_change(Side::Sell, dec!(0.52), dec!(0)),
+            book("2", dec!(0.45), dec!(0.55)),
+        ];
+
+        let states: Vec<_> = replay(events)
+            .map(|(_, book)| {
+                (
+                    book.best_bid().map(|level| level.price),
+                    book.best_ask().map(|level| level.price),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            states,
+            vec![
+                (Some(dec!(0.48)), Some(dec!(0.52))),
+                (Some(dec!(0.49)), Some(dec!(0.52))),
+                (Some(dec!(0.49)), None),
+                (Some(dec!(0.45)), Some(dec!(0.55))),
+            ]
+        );
+    }
+}
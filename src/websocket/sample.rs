@@ -0,0 +1,334 @@
+use futures_util::Stream;
+use std::collections::VecDeque;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::{interval, Interval, MissedTickBehavior};
+
+use crate::error::Result;
+use crate::types::{PriceChangeEvent, WsEvent};
+
+/// Kind of event held in a pending slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventKind {
+    Book,
+    PriceChange,
+    LastTradePrice,
+    TickSizeChange,
+}
+
+/// Sample market events so each asset emits at most once per event kind and window
+///
+/// Events are buffered per asset id and flushed on every `interval` tick:
+///
+/// - [`WsEvent::Book`], [`WsEvent::LastTradePrice`] and [`WsEvent::TickSizeChange`]
+///   keep only the latest event seen in the window
+/// - [`WsEvent::PriceChange`] deltas are merged with [`PriceChangeEvent::coalesce`]
+///   rather than dropped, so a [`LocalOrderBook`](crate::orderbook::LocalOrderBook)
+///   fed from the sampled stream stays consistent. Price change events touching
+///   several assets are split per asset.
+/// - A book snapshot discards pending price changes for the same asset, since the
+///   snapshot already reflects them
+///
+/// Every asset has its own slot, so a busy market never crowds out updates for a
+/// quiet one. Errors are passed through immediately and pending events are flushed
+/// when the inner stream ends.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polymarket_rs::websocket::{sample_per_asset, MarketWsClient};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = MarketWsClient::new();
+/// let stream = client.subscribe(vec!["token_id".to_string()]).await?;
+/// let mut sampled = Box::pin(sample_per_asset(stream, Duration::from_millis(100)));
+///
+/// while let Some(event) = sampled.next().await {
+///     println!("Event: {:?}", event?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn sample_per_asset<S>(stream: S, interval: Duration) -> impl Stream<Item = Result<WsEvent>>
+where
+    S: Stream<Item = Result<WsEvent>> + Unpin,
+{
+    SampledStream::new(stream, interval)
+}
+
+struct SampledStream<S> {
+    inner: S,
+    interval: Interval,
+    /// Pending events keyed by asset id and kind, in order of arrival
+    pending: Vec<(String, EventKind, WsEvent)>,
+    /// Events ready to be yielded
+    ready: VecDeque<WsEvent>,
+    /// Whether the inner stream has ended
+    done: bool,
+}
+
+impl<S> SampledStream<S>
+where
+    S: Stream<Item = Result<WsEvent>> + Unpin,
+{
+    fn new(inner: S, period: Duration) -> Self {
+        let mut interval = interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        Self {
+            inner,
+            interval,
+            pending: Vec::new(),
+            ready: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Buffer an event in its asset's slot
+    fn push(&mut self, event: WsEvent) {
+        match event {
+            WsEvent::Book(book) => {
+                let asset_id = book.asset_id.clone();
+                self.pending.retain(|(id, kind, _)| {
+                    id != &asset_id || !matches!(kind, EventKind::Book | EventKind::PriceChange)
+                });
+                self.pending
+                    .push((asset_id, EventKind::Book, WsEvent::Book(book)));
+            }
+            WsEvent::PriceChange(change) => {
+                for (asset_id, change) in split_by_asset(change) {
+                    self.push_price_change(asset_id, change);
+                }
+            }
+            WsEvent::LastTradePrice(trade) => {
+                let asset_id = trade.asset_id.clone();
+                self.replace(
+                    asset_id,
+                    EventKind::LastTradePrice,
+                    WsEvent::LastTradePrice(trade),
+                );
+            }
+            WsEvent::TickSizeChange(tick) => {
+                let asset_id = tick.asset_id.clone();
+                self.replace(
+                    asset_id,
+                    EventKind::TickSizeChange,
+                    WsEvent::TickSizeChange(tick),
+                );
+            }
+        }
+    }
+
+    /// Merge a single-asset price change into any pending one
+    fn push_price_change(&mut self, asset_id: String, change: PriceChangeEvent) {
+        let slot = self
+            .pending
+            .iter_mut()
+            .find(|(id, kind, _)| id == &asset_id && *kind == EventKind::PriceChange);
+
+        match slot {
+            Some((_, _, WsEvent::PriceChange(pending))) => {
+                *pending = PriceChangeEvent::coalesce(&[pending.clone(), change]);
+            }
+            _ => self.pending.push((
+                asset_id,
+                EventKind::PriceChange,
+                WsEvent::PriceChange(change),
+            )),
+        }
+    }
+
+    /// Replace the pending event of a kind, keeping its position
+    fn replace(&mut self, asset_id: String, kind: EventKind, event: WsEvent) {
+        match self
+            .pending
+            .iter_mut()
+            .find(|(id, k, _)| id == &asset_id && *k == kind)
+        {
+            Some(slot) => slot.2 = event,
+            None => self.pending.push((asset_id, kind, event)),
+        }
+    }
+
+    /// Move every pending event to the ready queue
+    fn flush(&mut self) {
+        let pending = mem::take(&mut self.pending);
+        self.ready
+            .extend(pending.into_iter().map(|(_, _, event)| event));
+    }
+}
+
+impl<S> Stream for SampledStream<S>
+where
+    S: Stream<Item = Result<WsEvent>> + Unpin,
+{
+    type Item = Result<WsEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.ready.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            // Buffer everything the inner stream has available right now
+            loop {
+                match Pin::new(&mut self.inner).poll_next(cx) {
+                    Poll::Ready(Some(Ok(event))) => self.push(event),
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                    Poll::Ready(None) => {
+                        self.done = true;
+                        self.flush();
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+            if self.done {
+                continue;
+            }
+
+            match self.interval.poll_tick(cx) {
+                Poll::Ready(_) => self.flush(),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Split a price change event into one event per asset id
+fn split_by_asset(event: PriceChangeEvent) -> Vec<(String, PriceChangeEvent)> {
+    let mut split: Vec<(String, PriceChangeEvent)> = Vec::new();
+
+    for change in event.price_changes {
+        match split.iter_mut().find(|(id, _)| id == &change.asset_id) {
+            Some((_, asset_event)) => asset_event.price_changes.push(change),
+            None => split.push((
+                change.asset_id.clone(),
+                PriceChangeEvent {
+                    market: event.market.clone(),
+                    timestamp: event.timestamp.clone(),
+                    hash: event.hash.clone(),
+                    price_changes: vec![change],
+                },
+            )),
+        }
+    }
+
+    split
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BookEvent, LastTradePriceEvent, PriceChange, Side};
+    use futures_util::{stream, StreamExt};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn book(asset_id: &str, timestamp: &str) -> WsEvent {
+        WsEvent::Book(BookEvent {
+            market: "0xmarket".to_string(),
+            asset_id: asset_id.to_string(),
+            timestamp: timestamp.to_string(),
+            hash: "0xhash".to_string(),
+            bids: vec![],
+            asks: vec![],
+            last_trade_price: None,
+        })
+    }
+
+    fn price_change(changes: &[(&str, Decimal, Decimal)]) -> WsEvent {
+        WsEvent::PriceChange(PriceChangeEvent {
+            market: "0xmarket".to_string(),
+            timestamp: None,
+            hash: None,
+            price_changes: changes
+                .iter()
+                .map(|(asset_id, price, size)| PriceChange {
+                    asset_id: asset_id.to_string(),
+                    side: Side::Buy,
+                    price: *price,
+                    size: *size,
+                })
+                .collect(),
+        })
+    }
+
+    fn trade(asset_id: &str, price: Decimal) -> WsEvent {
+        WsEvent::LastTradePrice(LastTradePriceEvent {
+            market: "0xmarket".to_string(),
+            asset_id: asset_id.to_string(),
+            price,
+            size: dec!(1),
+            fee_rate_bps: dec!(0),
+            side: Side::Buy,
+            timestamp: "1".to_string(),
+            transaction_hash: "0xtx".to_string(),
+        })
+    }
+
+    async fn sample(events: Vec<WsEvent>) -> Vec<WsEvent> {
+        let inner = stream::iter(events.into_iter().map(Ok));
+        sample_per_asset(inner, Duration::from_secs(60))
+            .map(|event| event.unwrap())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_keeps_latest_per_asset() {
+        let sampled = sample(vec![
+            trade("A", dec!(0.40)),
+            trade("B", dec!(0.60)),
+            trade("A", dec!(0.45)),
+        ])
+        .await;
+
+        assert_eq!(sampled.len(), 2);
+        match (&sampled[0], &sampled[1]) {
+            (WsEvent::LastTradePrice(a), WsEvent::LastTradePrice(b)) => {
+                assert_eq!((a.asset_id.as_str(), a.price), ("A", dec!(0.45)));
+                assert_eq!((b.asset_id.as_str(), b.price), ("B", dec!(0.60)));
+            }
+            other => panic!("unexpected events: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_price_changes_split_and_coalesced() {
+        let sampled = sample(vec![
+            price_change(&[("A", dec!(0.50), dec!(10)), ("B", dec!(0.30), dec!(5))]),
+            price_change(&[("A", dec!(0.50), dec!(0)), ("A", dec!(0.51), dec!(3))]),
+        ])
+        .await;
+
+        assert_eq!(sampled.len(), 2);
+        let WsEvent::PriceChange(a) = &sampled[0] else {
+            panic!("expected price change");
+        };
+        assert_eq!(a.price_changes.len(), 2);
+        assert!(a.price_changes.iter().all(|c| c.asset_id == "A"));
+        assert_eq!(a.price_changes[0].size, dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_book_supersedes_pending_price_changes() {
+        let sampled = sample(vec![
+            price_change(&[("A", dec!(0.50), dec!(10))]),
+            book("A", "2"),
+            price_change(&[("A", dec!(0.52), dec!(4))]),
+        ])
+        .await;
+
+        assert_eq!(sampled.len(), 2);
+        assert!(matches!(&sampled[0], WsEvent::Book(b) if b.timestamp == "2"));
+        assert!(matches!(&sampled[1], WsEvent::PriceChange(_)));
+    }
+}
@@ -17,6 +17,8 @@ pub struct ReconnectConfig {
     /// Multiplier for exponential backoff
     pub multiplier: f64,
     /// Maximum number of reconnection attempts (None = infinite)
+    ///
+    /// Once reached, the stream yields [`Error::ReconnectExhausted`] and ends.
     pub max_attempts: Option<u32>,
 }
 
@@ -78,6 +80,8 @@ enum StreamState<S, Fut> {
         attempts: u32,
         future: Option<Pin<Box<Fut>>>,
     },
+    /// Reconnection attempts ran out; the error is yielded before terminating
+    Exhausted { attempts: u32 },
     /// Stream has been terminated
     Terminated,
 }
@@ -87,7 +91,8 @@ enum StreamState<S, Fut> {
 /// This wrapper provides resilient streaming by:
 /// - Automatically reconnecting when the connection is lost
 /// - Using exponential backoff between reconnection attempts
-/// - Optionally limiting the number of reconnection attempts
+/// - Optionally limiting the number of reconnection attempts, ending with
+///   [`Error::ReconnectExhausted`] when they run out
 ///
 /// # Example
 ///
@@ -167,22 +172,20 @@ where
     }
 
     /// Handle a disconnection and prepare for reconnection
-    fn handle_disconnection(&mut self, attempts: u32) -> Poll<Option<Result<T>>> {
+    ///
+    /// Moves to [`StreamState::Exhausted`] if `attempts` reached the maximum.
+    fn handle_disconnection(&mut self, attempts: u32) {
         // Check if we've exceeded max attempts
         if let Some(max) = self.config.max_attempts {
             if attempts >= max {
-                self.state = StreamState::Terminated;
-                return Poll::Ready(Some(Err(Error::ReconnectFailed {
-                    attempts,
-                    last_error: "Maximum reconnection attempts reached".to_string(),
-                })));
+                self.state = StreamState::Exhausted { attempts };
+                return;
             }
         }
 
         let delay = self.backoff.next_delay();
         self.state = StreamState::Reconnecting { attempts, delay };
         self.sleep_future = Some(Box::pin(sleep(delay)));
-        Poll::Pending
     }
 }
 
@@ -206,16 +209,18 @@ where
                         }
                         Poll::Ready(Some(Err(Error::ConnectionClosed))) => {
                             // Connection closed, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            self.handle_disconnection(1);
+                            continue;
                         }
                         Poll::Ready(Some(Err(e))) => {
                             // Other error, pass through and prepare to reconnect
-                            let _ = self.handle_disconnection(1);
+                            self.handle_disconnection(1);
                             return Poll::Ready(Some(Err(e)));
                         }
                         Poll::Ready(None) => {
                             // Stream ended, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            self.handle_disconnection(1);
+                            continue;
                         }
                         Poll::Pending => {
                             return Poll::Pending;
@@ -269,7 +274,8 @@ where
                             // Connection failed, prepare to reconnect
                             // Increment attempts (or start at 1 if this is the first attempt)
                             let next_attempts = if current_attempts == 0 { 1 } else { current_attempts + 1 };
-                            return self.handle_disconnection(next_attempts);
+                            self.handle_disconnection(next_attempts);
+                            continue;
                         }
                         Poll::Pending => {
                             // Store the future for next poll
@@ -281,6 +287,11 @@ where
                         }
                     }
                 }
+                StreamState::Exhausted { attempts } => {
+                    let attempts = *attempts;
+                    self.state = StreamState::Terminated;
+                    return Poll::Ready(Some(Err(Error::ReconnectExhausted { attempts })));
+                }
                 StreamState::Terminated => {
                     return Poll::Ready(None);
                 }
@@ -292,6 +303,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures_util::{stream, StreamExt};
 
     #[test]
     fn test_backoff() {
@@ -337,4 +349,56 @@ mod tests {
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
     }
+
+    fn fast_config(max_attempts: u32) -> ReconnectConfig {
+        ReconnectConfig {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            multiplier: 1.0,
+            max_attempts: Some(max_attempts),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_yields_exhausted_error_then_ends() {
+        let mut stream = ReconnectingStream::new(fast_config(3), || async {
+            Err::<futures_util::stream::Empty<Result<u32>>, _>(Error::ConnectionClosed)
+        });
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::ReconnectExhausted { attempts: 3 }))
+        ));
+        assert!(stream.next().await.is_none());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_after_stream_error() {
+        let connects = std::sync::atomic::AtomicU32::new(0);
+        let mut stream = ReconnectingStream::new(fast_config(2), || {
+            let first = connects.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0;
+            async move {
+                if first {
+                    Ok(stream::iter(vec![
+                        Ok(1),
+                        Err(Error::WebSocket("reset".to_string())),
+                    ]))
+                } else {
+                    Err(Error::ConnectionClosed)
+                }
+            }
+        });
+
+        assert!(matches!(stream.next().await, Some(Ok(1))));
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::WebSocket(_)))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::ReconnectExhausted { attempts: 2 }))
+        ));
+        assert!(stream.next().await.is_none());
+    }
 }
@@ -1,10 +1,45 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::{stream, SinkExt, Stream, StreamExt};
 use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::{ReconnectConfig, ReconnectingStream};
 use crate::error::{Error, Result};
 use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 
+/// Handle for querying user WebSocket subscription state
+///
+/// Returned by [`UserWsClient::subscribe_with_handle`] and
+/// [`UserWsClient::subscribe_reconnecting`]. An empty market list means the
+/// subscription receives events for all markets.
+#[derive(Clone)]
+pub struct UserSubscriptionHandle {
+    /// Shared state containing the subscribed condition IDs
+    markets: Arc<RwLock<Vec<String>>>,
+}
+
+impl UserSubscriptionHandle {
+    fn new(markets: Vec<String>) -> Self {
+        Self {
+            markets: Arc::new(RwLock::new(markets)),
+        }
+    }
+
+    /// Get the market (condition) IDs this subscription is restricted to
+    pub fn subscribed_markets(&self) -> Vec<String> {
+        self.markets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Check whether events for a market (condition ID) are received
+    pub fn is_subscribed(&self, market: &str) -> bool {
+        let markets = self.markets.read().unwrap_or_else(PoisonError::into_inner);
+        markets.is_empty() || markets.iter().any(|m| m == market)
+    }
+}
+
 /// WebSocket client for streaming authenticated user events
 ///
 /// This client connects to the Polymarket CLOB user WebSocket endpoint and streams
@@ -62,6 +97,67 @@ pub struct UserWsClient {
     ws_url: String,
 }
 
+/// Parse a WebSocket message into UserWsEvents
+///
+/// Mirrors the market client's parsing: the server can batch events into a JSON
+/// array, so a single message can yield several events. Text `PING`/`PONG`
+/// keepalive messages are skipped.
+fn parse_user_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Vec<Result<UserWsEvent>> {
+    match msg {
+        Ok(Message::Text(text)) => {
+            // Skip empty messages and keepalive replies
+            let trimmed = text.trim();
+            if trimmed.is_empty()
+                || trimmed.eq_ignore_ascii_case("ping")
+                || trimmed.eq_ignore_ascii_case("pong")
+            {
+                return Vec::new();
+            }
+
+            // The server can send either a single object or an array
+            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(trimmed) {
+                return events
+                    .into_iter()
+                    .map(|event| serde_json::from_value::<UserWsEvent>(event).map_err(Error::Json))
+                    .collect();
+            }
+
+            vec![serde_json::from_str::<UserWsEvent>(trimmed).map_err(Error::Json)]
+        }
+        Ok(Message::Close(close_frame)) => {
+            // Connection closed - may indicate auth failure
+            if let Some(frame) = close_frame {
+                vec![Err(Error::WebSocket(format!(
+                    "Connection closed: code={}, reason={}",
+                    frame.code, frame.reason
+                )))]
+            } else {
+                vec![Err(Error::ConnectionClosed)]
+            }
+        }
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
+            // Ignore ping/pong frames (handled automatically)
+            Vec::new()
+        }
+        Ok(Message::Binary(_)) => {
+            // Unexpected binary message
+            vec![Err(Error::WebSocket(
+                "Unexpected binary message".to_string(),
+            ))]
+        }
+        Ok(Message::Frame(_)) => {
+            // Raw frame (shouldn't happen)
+            Vec::new()
+        }
+        Err(e) => {
+            // WebSocket error
+            vec![Err(Error::WebSocket(e.to_string()))]
+        }
+    }
+}
+
 impl UserWsClient {
     /// Default WebSocket URL for user events
     const DEFAULT_WS_URL: &'static str = "wss://ws-subscriptions-clob.polymarket.com/ws/user";
@@ -137,6 +233,107 @@ impl UserWsClient {
         .await
     }
 
+    /// Subscribe to user events for specific markets
+    ///
+    /// Like [`subscribe_with_creds`](Self::subscribe_with_creds), but the server only
+    /// sends trades and order updates for the given markets. An empty list receives
+    /// events for all markets.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - API credentials (api_key, secret, passphrase)
+    /// * `markets` - Condition IDs of the markets to receive events for
+    pub async fn subscribe_to_markets(
+        &self,
+        creds: &ApiCreds,
+        markets: Vec<String>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
+        let auth = UserAuthentication::new(
+            creds.api_key.clone(),
+            creds.secret.clone(),
+            creds.passphrase.clone(),
+        )
+        .with_markets(markets);
+        self.connect(auth).await
+    }
+
+    /// Subscribe to user events with a handle to query subscription state
+    ///
+    /// Same as [`subscribe_to_markets`](Self::subscribe_to_markets), also returning a
+    /// [`UserSubscriptionHandle`] for the markets the connection was opened with.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - API credentials (api_key, secret, passphrase)
+    /// * `markets` - Condition IDs of the markets to receive events for (empty = all)
+    pub async fn subscribe_with_handle(
+        &self,
+        creds: &ApiCreds,
+        markets: Vec<String>,
+    ) -> Result<(
+        Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>,
+        UserSubscriptionHandle,
+    )> {
+        let handle = UserSubscriptionHandle::new(markets.clone());
+        let stream = self.subscribe_to_markets(creds, markets).await?;
+        Ok((stream, handle))
+    }
+
+    /// Subscribe to user events, reconnecting automatically
+    ///
+    /// Wraps the connection in a [`ReconnectingStream`]. Every reconnect opens a
+    /// new connection and sends the authentication message again, restricted to
+    /// the markets of the returned handle. The connection is only opened once the
+    /// stream is first polled.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - API credentials (api_key, secret, passphrase)
+    /// * `markets` - Condition IDs of the markets to receive events for (empty = all)
+    /// * `config` - Reconnection behavior
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::websocket::{ReconnectConfig, UserWsClient};
+    /// # use polymarket_rs::types::ApiCreds;
+    /// # use futures_util::StreamExt;
+    /// # async fn example(creds: ApiCreds) {
+    /// let client = UserWsClient::new();
+    /// let (mut stream, _handle) =
+    ///     client.subscribe_reconnecting(creds, Vec::new(), ReconnectConfig::default());
+    ///
+    /// while let Some(event) = stream.next().await {
+    ///     match event {
+    ///         Ok(event) => println!("Event: {:?}", event),
+    ///         Err(e) => eprintln!("Error, reconnecting: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe_reconnecting(
+        &self,
+        creds: ApiCreds,
+        markets: Vec<String>,
+        config: ReconnectConfig,
+    ) -> (
+        Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>,
+        UserSubscriptionHandle,
+    ) {
+        let handle = UserSubscriptionHandle::new(markets);
+        let client = self.clone();
+        let subscription = handle.clone();
+
+        let stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            let creds = creds.clone();
+            let markets = subscription.subscribed_markets();
+            async move { client.subscribe_to_markets(&creds, markets).await }
+        });
+
+        (Box::pin(stream), handle)
+    }
+
     /// Subscribe to user events with authentication
     ///
     /// Returns a stream of [`UserWsEvent`] items. The stream will yield events as they
@@ -165,15 +362,22 @@ impl UserWsClient {
         api_key: String,
         api_secret: String,
         api_passphrase: String,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
+        // Create authentication message
+        let auth = UserAuthentication::new(api_key, api_secret, api_passphrase);
+        self.connect(auth).await
+    }
+
+    /// Connect, authenticate and return the parsed event stream
+    async fn connect(
+        &self,
+        auth: UserAuthentication,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
         // Connect to the WebSocket endpoint
         let (ws_stream, _) = connect_async(&self.ws_url).await?;
 
         let (mut write, read) = ws_stream.split();
 
-        // Create authentication message
-        let auth = UserAuthentication::new(api_key, api_secret, api_passphrase);
-
         let auth_msg = serde_json::to_string(&auth)?;
 
         // Send authentication message
@@ -183,61 +387,7 @@ impl UserWsClient {
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
         // Return stream that parses events
-        let stream = read.filter_map(|msg| async move {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // The server can send either a single object or an array
-                    // Try to parse as array first
-                    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                        // Got an array, take the first event
-                        if let Some(first) = events.first() {
-                            match serde_json::from_value::<UserWsEvent>(first.clone()) {
-                                Ok(event) => return Some(Ok(event)),
-                                Err(e) => return Some(Err(Error::Json(e))),
-                            }
-                        } else {
-                            // Empty array, ignore
-                            return None;
-                        }
-                    }
-
-                    // Try parsing as single object
-                    match serde_json::from_str::<UserWsEvent>(&text) {
-                        Ok(event) => Some(Ok(event)),
-                        Err(e) => Some(Err(Error::Json(e))),
-                    }
-                }
-                Ok(Message::Close(close_frame)) => {
-                    // Connection closed - may indicate auth failure
-                    if let Some(frame) = close_frame {
-                        Some(Err(Error::WebSocket(format!(
-                            "Connection closed: code={}, reason={}",
-                            frame.code, frame.reason
-                        ))))
-                    } else {
-                        Some(Err(Error::ConnectionClosed))
-                    }
-                }
-                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-                    // Ignore ping/pong frames (handled automatically)
-                    None
-                }
-                Ok(Message::Binary(_)) => {
-                    // Unexpected binary message
-                    Some(Err(Error::WebSocket(
-                        "Unexpected binary message".to_string(),
-                    )))
-                }
-                Ok(Message::Frame(_)) => {
-                    // Raw frame (shouldn't happen)
-                    None
-                }
-                Err(e) => {
-                    // WebSocket error
-                    Some(Err(Error::WebSocket(e.to_string())))
-                }
-            }
-        });
+        let stream = read.flat_map(|msg| stream::iter(parse_user_message(msg)));
 
         Ok(Box::pin(stream))
     }
@@ -258,4 +408,28 @@ mod tests {
         let client = UserWsClient::new();
         assert_eq!(client.ws_url, UserWsClient::DEFAULT_WS_URL);
     }
+
+    #[test]
+    fn test_handle_market_filter() {
+        let all = UserSubscriptionHandle::new(Vec::new());
+        assert!(all.is_subscribed("0xabc"));
+
+        let some = UserSubscriptionHandle::new(vec!["0xabc".to_string()]);
+        assert!(some.is_subscribed("0xabc"));
+        assert!(!some.is_subscribed("0xdef"));
+        assert_eq!(some.subscribed_markets(), vec!["0xabc".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_skips_pong_and_yields_every_batched_event() {
+        assert!(parse_user_message(Ok(Message::Text("PONG".into()))).is_empty());
+
+        let order = r#"{"event_type":"order","id":"0x1","owner":"o","market":"0xm","asset_id":"1","side":"BUY","original_size":"10","size_matched":"0","price":"0.5","associate_trades":null,"outcome":"Yes","type":"PLACEMENT","created_at":"1","expiration":"0","order_type":"GTC","status":"LIVE","maker_address":"0xa","timestamp":"1"}"#;
+        let batch = format!("[{},{}]", order, order);
+        let events = parse_user_message(Ok(Message::Text(batch)));
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|e| matches!(e, Ok(UserWsEvent::Order(_)))));
+    }
 }